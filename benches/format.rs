@@ -0,0 +1,59 @@
+use compound_duration::{format_dhms, format_dhms_small, format_ns};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Counts the allocations to compare the `String` and `SmallString` paths
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+const INPUTS: [u64; 4] = [0, 61, 6_000_000, u64::MAX];
+
+/// Allocations made by `f` for every input
+fn allocations<R>(f: impl Fn(u64) -> R) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for &seconds in &INPUTS {
+        black_box(f(black_box(seconds)));
+    }
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn bench_format(c: &mut Criterion) {
+    println!(
+        "allocations for {} inputs: format_dhms {}, format_dhms_small {}",
+        INPUTS.len(),
+        allocations(format_dhms),
+        allocations(format_dhms_small)
+    );
+
+    let mut group = c.benchmark_group("dhms");
+    group.bench_function("format_dhms", |b| {
+        b.iter(|| INPUTS.map(|seconds| format_dhms(black_box(seconds))))
+    });
+    group.bench_function("format_dhms_small", |b| {
+        b.iter(|| INPUTS.map(|seconds| format_dhms_small(black_box(seconds))))
+    });
+    group.finish();
+
+    c.bench_function("format_ns", |b| {
+        b.iter(|| format_ns(black_box(3_000_129_723_u64)))
+    });
+}
+
+criterion_group!(benches, bench_format);
+criterion_main!(benches);
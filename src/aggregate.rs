@@ -0,0 +1,189 @@
+use crate::{format_ns_u128, write_dhms, write_ns};
+use alloc::string::String;
+use core::fmt::Write;
+use core::time::Duration;
+
+/// Convert the largest of `secs` to compound duration (days, hours, minutes,
+/// seconds)
+///
+/// An empty slice is `"0s"`.
+///
+/// Example:
+///```
+/// use compound_duration::format_max;
+///
+/// assert_eq!(format_max(&[61, 6_000_000, 3600]), "69d10h40m");
+/// assert_eq!(format_max(&[]), "0s");
+///```
+#[must_use]
+pub fn format_max(secs: &[u64]) -> String {
+    format_extreme(secs.iter().max())
+}
+
+/// Convert the smallest of `secs` to compound duration (days, hours, minutes,
+/// seconds)
+///
+/// An empty slice is `"0s"`.
+///
+/// Example:
+///```
+/// use compound_duration::format_min;
+///
+/// assert_eq!(format_min(&[61, 6_000_000, 3600]), "1m1s");
+/// assert_eq!(format_min(&[]), "0s");
+///```
+#[must_use]
+pub fn format_min(secs: &[u64]) -> String {
+    format_extreme(secs.iter().min())
+}
+
+/// Convert the sum of `durations` to compound duration (days, hours, minutes,
+/// seconds, ms, µs, ns)
+///
+/// The total is accumulated in `u128` nanoseconds, so it can exceed
+/// `u64::MAX` nanoseconds without overflowing. An empty iterator is `"0ns"`, same as
+/// [`format_ns`](crate::format_ns) for zero.
+///
+/// Example:
+///```
+/// use compound_duration::format_sum;
+/// use std::time::Duration;
+///
+/// let timings = [Duration::from_millis(1500), Duration::from_secs(59)];
+/// assert_eq!(format_sum(timings), "1m500ms");
+/// assert_eq!(format_sum(Vec::new()), "0ns");
+///```
+#[must_use]
+pub fn format_sum<I: IntoIterator<Item = Duration>>(durations: I) -> String {
+    format_ns_u128(durations.into_iter().map(|d| d.as_nanos()).sum())
+}
+
+/// Convert nanoseconds to compound duration (days, hours, minutes, seconds,
+/// ms, µs, ns) followed by its share of `total`
+///
+/// The duration is the same as [`format_ns`](crate::format_ns), the share is
+/// `nanos / total` as a percentage rounded half up to one decimal place, in
+/// parentheses after a space. A `total` of zero has no share and is shown as
+/// `"(n/a)"`. `nanos` larger than `total` gives more than `100%`.
+///
+/// Example:
+///```
+/// use compound_duration::format_with_share;
+///
+/// assert_eq!(format_with_share(3_000_129_000, 24_001_032_000), "3s129µs (12.5%)");
+/// assert_eq!(format_with_share(61_000_000_000, 0), "1m1s (n/a)");
+///```
+#[must_use]
+pub fn format_with_share(nanos: u64, total: u64) -> String {
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_ns(&mut compound_duration, nanos).unwrap();
+    if total == 0 {
+        compound_duration.push_str(" (n/a)");
+    } else {
+        // tenths of a percent, rounded half up
+        let (nanos, total) = (u128::from(nanos), u128::from(total));
+        let tenths = (nanos * 2000 + total) / (total * 2);
+        write!(compound_duration, " ({}.{}%)", tenths / 10, tenths % 10).unwrap();
+    }
+    compound_duration
+}
+
+fn format_extreme(seconds: Option<&u64>) -> String {
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_dhms(&mut compound_duration, seconds.copied().unwrap_or(0)).unwrap();
+    compound_duration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_max, format_min, format_sum, format_with_share};
+    use crate::{format_dhms, format_ns, format_ns_u128};
+    use alloc::format;
+    use alloc::vec::Vec;
+    use core::time::Duration;
+
+    #[test]
+    fn test_format_max() {
+        assert_eq!(format_max(&[]), "0s");
+        assert_eq!(format_max(&[0]), "0s");
+        assert_eq!(format_max(&[61]), "1m1s");
+        assert_eq!(format_max(&[61, 61]), "1m1s");
+        assert_eq!(format_max(&[61, 6_000_000, 3600]), "69d10h40m");
+        assert_eq!(format_max(&[0, u64::MAX]), format_dhms(u64::MAX));
+
+        let secs: Vec<u64> = (0..1000).map(|i| i * 997).collect();
+        assert_eq!(format_max(&secs), format_dhms(999 * 997));
+    }
+
+    #[test]
+    fn test_format_min() {
+        assert_eq!(format_min(&[]), "0s");
+        assert_eq!(format_min(&[0]), "0s");
+        assert_eq!(format_min(&[61]), "1m1s");
+        assert_eq!(format_min(&[61, 61]), "1m1s");
+        assert_eq!(format_min(&[61, 6_000_000, 3600]), "1m1s");
+        assert_eq!(format_min(&[u64::MAX, 0]), "0s");
+        assert_eq!(format_min(&[u64::MAX]), format_dhms(u64::MAX));
+
+        let secs: Vec<u64> = (1..1000).rev().map(|i| i * 997).collect();
+        assert_eq!(format_min(&secs), format_dhms(997));
+    }
+
+    #[test]
+    fn test_format_sum() {
+        assert_eq!(format_sum(Vec::new()), "0ns");
+        assert_eq!(format_sum([Duration::ZERO]), "0ns");
+        assert_eq!(format_sum([Duration::new(61, 1_000_001)]), "1m1s1ms1ns");
+        assert_eq!(
+            format_sum([Duration::from_millis(1500), Duration::from_secs(59)]),
+            "1m500ms"
+        );
+        assert_eq!(
+            format_sum((0..1000).map(|_| Duration::from_micros(1))),
+            "1ms"
+        );
+
+        // the total exceeds u64::MAX nanoseconds
+        let max = Duration::from_nanos(u64::MAX);
+        let total = u128::from(u64::MAX) * 3;
+        assert_eq!(format_sum([max, max, max]), format_ns_u128(total));
+        assert_eq!(
+            format_sum([max, max, max]),
+            "640511d22h43m41s128ms654µs845ns"
+        );
+        assert_eq!(
+            format_sum([Duration::MAX, Duration::MAX]),
+            format_ns_u128(Duration::MAX.as_nanos() * 2)
+        );
+    }
+
+    #[test]
+    fn test_format_with_share() {
+        assert_eq!(
+            format_with_share(3_000_129_000, 24_001_032_000),
+            "3s129\u{b5}s (12.5%)"
+        );
+        assert_eq!(format_with_share(0, 100), "0ns (0.0%)");
+        assert_eq!(format_with_share(100, 100), "100ns (100.0%)");
+        assert_eq!(format_with_share(1, 3), "1ns (33.3%)");
+        assert_eq!(format_with_share(2, 3), "2ns (66.7%)");
+        // half up
+        assert_eq!(format_with_share(1, 2000), "1ns (0.1%)");
+        assert_eq!(format_with_share(1, 2001), "1ns (0.0%)");
+        assert_eq!(format_with_share(300, 100), "300ns (300.0%)");
+        assert_eq!(
+            format_with_share(u64::MAX, u64::MAX),
+            format!("{} (100.0%)", format_ns(u64::MAX))
+        );
+        assert_eq!(
+            format_with_share(u64::MAX, 1),
+            format!("{} (1844674407370955161500.0%)", format_ns(u64::MAX))
+        );
+
+        // no share of nothing
+        assert_eq!(format_with_share(0, 0), "0ns (n/a)");
+        assert_eq!(format_with_share(61_000_000_000, 0), "1m1s (n/a)");
+    }
+}
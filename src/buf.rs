@@ -0,0 +1,46 @@
+use core::fmt;
+
+/// Longest `format_dhms` output, `u64::MAX` is `"213503982334601d7h15s"` but
+/// every unit can be two digits long
+#[cfg(feature = "std")]
+pub(crate) const MAX_DHMS_LEN: usize = "213503982334601d23h59m59s".len();
+
+/// Longest `format_dhms_long` output, bounded the same way as [`MAX_DHMS_LEN`]
+pub(crate) const MAX_DHMS_LONG_LEN: usize =
+    "213503982334601 days 23 hours 59 minutes 59 seconds".len();
+
+/// A fixed size [`fmt::Write`] buffer on the stack
+pub(crate) struct StackBuf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> StackBuf<N> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        // only whole `&str`s are ever copied in
+        core::str::from_utf8(self.as_bytes()).unwrap()
+    }
+}
+
+impl<const N: usize> fmt::Write for StackBuf<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
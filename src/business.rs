@@ -0,0 +1,118 @@
+use crate::{to_u64, DurationFormatter, SECONDS_PER_HOUR};
+use alloc::string::String;
+use core::convert::{TryFrom, TryInto};
+use core::fmt::Debug;
+use core::ops::BitAnd;
+
+/// Convert seconds of work to compound duration (weeks, days, hours, minutes,
+/// seconds) where a day is `hours_per_day` hours and a week is
+/// `days_per_week` of those days
+///
+/// Meant for time tracking, with 8 hour days and 5 day weeks `"1w 2d 3h"`
+/// is 59 hours of work. The units are allocated greedily like in
+/// [`format_wdhms`](crate::format_wdhms), the remainder of a week is always
+/// less than `days_per_week` days and the remainder of a day less than
+/// `hours_per_day` hours, 10 hours are `"1d 2h"`, never `"10h"`. The units
+/// are separated by a space.
+///
+/// When `hours_per_day` is `1` a day is as long as an hour and only hours are
+/// used, when `days_per_week` is `1` a week is as long as a day and only days
+/// are used.
+///
+/// # Panics
+///
+/// Panics if `hours_per_day` or `days_per_week` is zero.
+///
+/// Example:
+///```
+/// use compound_duration::format_business;
+///
+/// assert_eq!(format_business(59 * 3600, 8, 5), "1w 2d 3h");
+/// assert_eq!(format_business(10 * 3600 + 30, 8, 5), "1d 2h 30s");
+///```
+#[must_use]
+pub fn format_business<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    hours_per_day: u64,
+    days_per_week: u64,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    assert!(hours_per_day != 0, "a workday can't be zero hours long");
+    assert!(days_per_week != 0, "a workweek can't be zero days long");
+
+    let mut formatter = DurationFormatter::new().with_days(false).separator(" ");
+    // a unit too long for a u64 can never be used, so it's left out
+    if let Some(day) = hours_per_day.checked_mul(SECONDS_PER_HOUR as u64) {
+        // registered before the week so it goes first if both are as long
+        // as an hour
+        formatter = formatter.with_unit("d", day);
+        if let Some(week) = day.checked_mul(days_per_week) {
+            formatter = formatter.with_unit("w", week);
+        }
+    }
+    formatter.format(to_u64(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_business;
+    use crate::{format_dhms_sep, format_wdhms, DurationFormatter};
+
+    #[test]
+    fn test_format_business() {
+        let hour = 3600;
+        assert_eq!(format_business(0, 8, 5), "0s");
+        assert_eq!(format_business(1, 8, 5), "1s");
+        assert_eq!(format_business(hour, 8, 5), "1h");
+        assert_eq!(format_business(7 * hour + 59 * 60 + 59, 8, 5), "7h 59m 59s");
+        assert_eq!(format_business(8 * hour, 8, 5), "1d");
+        assert_eq!(format_business(10 * hour, 8, 5), "1d 2h");
+        assert_eq!(format_business(40 * hour, 8, 5), "1w");
+        assert_eq!(format_business(59 * hour, 8, 5), "1w 2d 3h");
+        assert_eq!(format_business(39 * hour, 8, 5), "4d 7h");
+        assert_eq!(format_business(86_400, 8, 5), "3d");
+        assert_eq!(
+            format_business(u64::MAX, 8, 5),
+            "128102389400760w 3d 7h 15s"
+        );
+
+        // a 24 hour day and a 7 day week are the calendar units
+        let calendar = DurationFormatter::new().with_weeks(true).separator(" ");
+        for seconds in (0..1000).map(|i| i * (u64::MAX / 1000)) {
+            assert_eq!(format_business(seconds, 24, 7), calendar.format(seconds));
+        }
+        assert_eq!(format_business(6_000_000, 24, 7), "9w 6d 10h 40m");
+        assert_eq!(
+            format_business(6_000_000, 24, 7).replace(' ', ""),
+            format_wdhms(6_000_000)
+        );
+
+        // one day weeks are only days and one hour days only hours
+        assert_eq!(format_business(25 * hour, 24, 1), "1d 1h");
+        assert_eq!(format_business(25 * hour + 1, 1, 5), "5w 1s");
+        assert_eq!(format_business(3 * hour + 61, 1, 1), "3h 1m 1s");
+        assert_eq!(format_business(7259, 1, 1), format_dhms_sep(7259, " "));
+
+        // units too long for a u64 are never used
+        assert_eq!(
+            format_business(u64::MAX, u64::MAX, 5),
+            "5124095576030431h 15s"
+        );
+        assert_eq!(format_business(8 * hour, 8, u64::MAX), "1d");
+    }
+
+    #[test]
+    #[should_panic(expected = "a workday can't be zero hours long")]
+    fn test_format_business_zero_hours() {
+        let _ = format_business(3600, 0, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "a workweek can't be zero days long")]
+    fn test_format_business_zero_days() {
+        let _ = format_business(3600, 8, 0);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{to_u64, write_wdhms, MONTH, SECONDS_PER_DAY, SECONDS_PER_HOUR, YEAR};
+use alloc::string::String;
+use core::convert::{TryFrom, TryInto};
+use core::fmt::{Debug, Write};
+use core::ops::BitAnd;
+
+/// Length of the year used by [`format_ywdhms_with`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum YearLength {
+    /// 365 days, same as [`YEAR`]
+    #[default]
+    Calendar365,
+    /// 365.25 days, the average year in the Julian calendar
+    Julian365_25,
+    /// 365.2425 days, the average year in the Gregorian calendar
+    Gregorian,
+}
+
+impl YearLength {
+    /// Return the length of the year in seconds
+    ///
+    /// All the supported years are a whole number of seconds, 365.25 days is
+    /// 365 days and 6 hours, 365.2425 days is 365 days, 5 hours, 49 minutes and
+    /// 12 seconds.
+    #[must_use]
+    pub const fn as_secs(self) -> u64 {
+        match self {
+            Self::Calendar365 => YEAR as u64,
+            Self::Julian365_25 => (365 * SECONDS_PER_DAY + 6 * SECONDS_PER_HOUR) as u64,
+            Self::Gregorian => 31_556_952,
+        }
+    }
+}
+
+/// Convert seconds to compound duration (years, months, week, days, hours,
+/// minutes, seconds)
+///
+/// Months and years don't have a fixed length, a [`MONTH`] is 30 days and a
+/// [`YEAR`] is 365 days. Months use the `mo` suffix so they can't be mistaken
+/// for minutes.
+///
+/// Example:
+///```
+/// use compound_duration::format_ywdhms;
+///
+/// // 7101w3d6h28m15s
+/// assert_eq!(format_ywdhms(4_294_967_295_u64), "136y2mo1w3d6h28m15s");
+///```
+#[must_use]
+pub fn format_ywdhms<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(seconds: T) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    format_ywdhms_with(seconds, YearLength::Calendar365)
+}
+
+/// Convert seconds to compound duration (years, months, week, days, hours,
+/// minutes, seconds) using the given [`YearLength`]
+///
+/// Whole years are taken first and whatever is left, including the fraction
+/// of a day of a Julian or Gregorian year, is split into months (30 days),
+/// weeks, days, hours, minutes and seconds. So with
+/// [`YearLength::Julian365_25`] 365 days are `"12mo5d"`, 6 hours short of a
+/// year.
+///
+/// Example:
+///```
+/// use compound_duration::{format_ywdhms_with, YearLength};
+///
+/// let four_years = 1461 * 86_400;
+/// assert_eq!(format_ywdhms_with(four_years, YearLength::Calendar365), "4y1d");
+/// assert_eq!(format_ywdhms_with(four_years, YearLength::Julian365_25), "4y");
+///```
+#[must_use]
+pub fn format_ywdhms_with<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    year_length: YearLength,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let seconds = to_u64(seconds);
+    let mut compound_duration = String::new();
+    if seconds == 0 {
+        compound_duration.push_str("0s");
+        return compound_duration;
+    }
+
+    let (year, month) = (year_length.as_secs(), MONTH as u64);
+
+    // years
+    let ys = seconds / year;
+    if ys != 0 {
+        // writing to a String never fails
+        write!(compound_duration, "{ys}y").unwrap();
+    }
+
+    // months
+    let mos = seconds % year / month;
+    if mos != 0 {
+        write!(compound_duration, "{mos}mo").unwrap();
+    }
+
+    // weeks and below, a month is always shorter than 5 weeks
+    let sec = seconds % year % month;
+    if sec != 0 {
+        write_wdhms(&mut compound_duration, sec).unwrap();
+    }
+
+    compound_duration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_ywdhms, format_ywdhms_with, YearLength};
+    use crate::{format_wdhms, MONTH, SECONDS_PER_DAY, SECONDS_PER_HOUR, YEAR};
+
+    #[test]
+    fn test_format_ywdhms() {
+        assert_eq!(format_ywdhms(0), "0s");
+        assert_eq!(format_ywdhms(61), "1m1s");
+        assert_eq!(format_ywdhms(29 * SECONDS_PER_DAY), "4w1d");
+        assert_eq!(format_ywdhms(MONTH), "1mo");
+        assert_eq!(format_ywdhms(MONTH + 60), "1mo1m");
+        assert_eq!(format_ywdhms(YEAR), "1y");
+        assert_eq!(format_ywdhms(YEAR + 86_399), "1y23h59m59s");
+        assert_eq!(format_ywdhms(6_000_000), "2mo1w2d10h40m");
+        assert_eq!(format_ywdhms(4_294_967_295_usize), "136y2mo1w3d6h28m15s");
+        for seconds in (0..MONTH).step_by(9973) {
+            assert_eq!(format_ywdhms(seconds), format_wdhms(seconds));
+        }
+    }
+
+    #[test]
+    fn test_format_ywdhms_with() {
+        let four_years = 4 * 365 * SECONDS_PER_DAY + 4 * 6 * SECONDS_PER_HOUR;
+        assert_eq!(four_years, 126_230_400);
+        assert_eq!(
+            format_ywdhms_with(four_years, YearLength::Calendar365),
+            "4y1d"
+        );
+        assert_eq!(
+            format_ywdhms_with(four_years, YearLength::Julian365_25),
+            "4y"
+        );
+        assert_eq!(
+            format_ywdhms_with(four_years, YearLength::Gregorian),
+            "4y43m12s"
+        );
+
+        // the fraction of the day goes into the hours
+        assert_eq!(format_ywdhms_with(YEAR, YearLength::Julian365_25), "12mo5d");
+        assert_eq!(
+            format_ywdhms_with(YEAR * 2, YearLength::Julian365_25),
+            "1y12mo4d18h"
+        );
+        assert_eq!(
+            format_ywdhms_with(YEAR * 2, YearLength::Gregorian),
+            "1y12mo4d18h10m48s"
+        );
+
+        assert_eq!(format_ywdhms_with(0, YearLength::Gregorian), "0s");
+        assert_eq!(YearLength::default(), YearLength::Calendar365);
+        for seconds in (0..four_years * 2).step_by(99_991) {
+            assert_eq!(
+                format_ywdhms_with(seconds, YearLength::default()),
+                format_ywdhms(seconds)
+            );
+        }
+    }
+}
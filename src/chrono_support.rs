@@ -0,0 +1,67 @@
+use crate::NS_FORMATTER;
+use alloc::string::String;
+use chrono::Duration;
+
+/// Convert a [`chrono::Duration`] to compound duration (days, hours, minutes,
+/// seconds, ms, µs, ns)
+///
+/// `chrono::Duration` keeps nanosecond resolution, so the output goes down to
+/// nanoseconds like [`format_ns`](crate::format_ns). Negative durations are
+/// prefixed with `-`, zero is `"0ns"` without a sign.
+///
+/// Requires the `chrono` feature.
+///
+/// Example:
+///```
+/// use chrono::Duration;
+/// use compound_duration::format_chrono;
+///
+/// assert_eq!(format_chrono(Duration::seconds(6_000_000)), "69d10h40m");
+/// assert_eq!(format_chrono(Duration::milliseconds(-1500)), "-1s500ms");
+///```
+#[must_use]
+pub fn format_chrono(d: Duration) -> String {
+    let nanos = i128::from(d.num_seconds()) * 1_000_000_000 + i128::from(d.subsec_nanos());
+
+    let mut compound_duration = String::new();
+    if nanos < 0 {
+        compound_duration.push('-');
+    }
+    // writing to a String never fails
+    NS_FORMATTER
+        .write_total(&mut compound_duration, nanos.unsigned_abs(), 1)
+        .unwrap();
+    compound_duration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_chrono;
+    use chrono::Duration;
+
+    #[test]
+    fn test_format_chrono() {
+        assert_eq!(format_chrono(Duration::zero()), "0ns");
+        assert_eq!(format_chrono(Duration::nanoseconds(1)), "1ns");
+        assert_eq!(format_chrono(Duration::seconds(61)), "1m1s");
+        assert_eq!(format_chrono(Duration::seconds(6_000_000)), "69d10h40m");
+        assert_eq!(
+            format_chrono(Duration::nanoseconds(3_000_129_723)),
+            "3s129\u{b5}s723ns"
+        );
+
+        // negative values, including under a second
+        assert_eq!(format_chrono(Duration::nanoseconds(-1)), "-1ns");
+        assert_eq!(format_chrono(Duration::milliseconds(-250)), "-250ms");
+        assert_eq!(format_chrono(Duration::milliseconds(-1500)), "-1s500ms");
+        assert_eq!(
+            format_chrono(Duration::nanoseconds(-3_000_129_723)),
+            "-3s129\u{b5}s723ns"
+        );
+        assert_eq!(format_chrono(Duration::seconds(-6_000_000)), "-69d10h40m");
+
+        // the limits are +/- i64::MAX milliseconds
+        assert_eq!(format_chrono(Duration::MAX), "106751991167d7h12m55s807ms");
+        assert_eq!(format_chrono(Duration::MIN), "-106751991167d7h12m55s807ms");
+    }
+}
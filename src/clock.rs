@@ -0,0 +1,111 @@
+use crate::{to_u64, SECONDS_PER_HOUR, SECONDS_PER_MINUTE};
+use alloc::format;
+use alloc::string::String;
+use core::convert::{TryFrom, TryInto};
+use core::fmt::Debug;
+use core::ops::BitAnd;
+
+/// Convert seconds to a clock style `HH:MM:SS` duration
+///
+/// Every field is zero padded to two digits. Days are rolled into the hours,
+/// so the hours can be larger than 23 and use more than two digits, a day and
+/// one hour is `"25:00:00"`.
+///
+/// When `always_show_hours` is `false` the hours are left out for durations
+/// under an hour, `"05:09"` instead of `"00:05:09"`.
+///
+/// Example:
+///```
+/// use compound_duration::format_clock;
+///
+/// assert_eq!(format_clock(3723, true), "01:02:03");
+/// assert_eq!(format_clock(309, true), "00:05:09");
+/// assert_eq!(format_clock(309, false), "05:09");
+/// assert_eq!(format_clock(90_000, false), "25:00:00");
+///```
+#[must_use]
+pub fn format_clock<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    always_show_hours: bool,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let seconds = to_u64(seconds);
+    let (hour, minute) = (SECONDS_PER_HOUR as u64, SECONDS_PER_MINUTE as u64);
+
+    let hs = seconds / hour;
+    let ms = seconds % hour / minute;
+    let sec = seconds % minute;
+
+    if hs == 0 && !always_show_hours {
+        format!("{ms:02}:{sec:02}")
+    } else {
+        format!("{hs:02}:{ms:02}:{sec:02}")
+    }
+}
+
+/// Convert seconds to a stopwatch style duration in minutes and seconds
+///
+/// Minutes are the largest unit, hours and days are intentionally never used,
+/// so an hour and a half is `"90m0s"`. Both units are always shown, zero is
+/// `"0m0s"`.
+///
+/// Example:
+///```
+/// use compound_duration::format_ms;
+///
+/// assert_eq!(format_ms(2400), "40m0s");
+/// assert_eq!(format_ms(6_000_000), "100000m0s");
+///```
+#[must_use]
+pub fn format_ms<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(seconds: T) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let seconds = to_u64(seconds);
+    let minute = SECONDS_PER_MINUTE as u64;
+    format!("{}m{}s", seconds / minute, seconds % minute)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_clock, format_ms};
+
+    #[test]
+    fn test_format_clock() {
+        assert_eq!(format_clock(0, true), "00:00:00");
+        assert_eq!(format_clock(0, false), "00:00");
+        assert_eq!(format_clock(59, false), "00:59");
+        assert_eq!(format_clock(309, true), "00:05:09");
+        assert_eq!(format_clock(309, false), "05:09");
+        assert_eq!(format_clock(3599, false), "59:59");
+        assert_eq!(format_clock(3600, false), "01:00:00");
+        assert_eq!(format_clock(3723, true), "01:02:03");
+        assert_eq!(format_clock(86_399, true), "23:59:59");
+
+        // days are rolled into the hours
+        assert_eq!(format_clock(86_400, true), "24:00:00");
+        assert_eq!(format_clock(90_000, false), "25:00:00");
+        assert_eq!(format_clock(6_000_000, true), "1666:40:00");
+    }
+
+    #[test]
+    fn test_format_ms() {
+        assert_eq!(format_ms(0), "0m0s");
+        assert_eq!(format_ms(45), "0m45s");
+        assert_eq!(format_ms(60), "1m0s");
+        assert_eq!(format_ms(2400), "40m0s");
+        assert_eq!(format_ms(3599), "59m59s");
+
+        // never rolls into hours or days
+        assert_eq!(format_ms(3600), "60m0s");
+        assert_eq!(format_ms(5430), "90m30s");
+        assert_eq!(format_ms(86_401), "1440m1s");
+        assert_eq!(format_ms(6_000_000), "100000m0s");
+        assert!(!format_ms(6_000_000).contains('h'));
+        assert_eq!(format_ms(u64::MAX), "307445734561825860m15s");
+    }
+}
@@ -0,0 +1,495 @@
+use crate::{
+    dhms_parts, subsec_parts, to_u64, Unit, NANOS_PER_SECOND, SECONDS_PER_DAY, SECONDS_PER_HOUR,
+    SECONDS_PER_MINUTE, SECONDS_PER_WEEK,
+};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::{TryFrom, TryInto};
+use core::fmt::Debug;
+use core::ops::BitAnd;
+
+/// Numeric breakdown of a duration (week, days, hours, minutes, seconds)
+///
+/// Components are ordered by the total elapsed time they add up to, not by
+/// comparing the fields one by one, so `2h` is less than `1d` and `1w` is
+/// less than `8d`. Equal totals like `1w` and `7d` fall back to comparing the
+/// fields, largest unit first, so the ordering stays consistent with `Eq`.
+///
+/// Example:
+///```
+/// use compound_duration::{components_wdhms, DurationComponents};
+///
+/// assert_eq!(
+///     components_wdhms(6_000_000),
+///     DurationComponents {
+///         weeks: 9,
+///         days: 6,
+///         hours: 10,
+///         minutes: 40,
+///         seconds: 0,
+///     }
+/// );
+///```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DurationComponents {
+    pub weeks: u64,
+    pub days: u64,
+    pub hours: u64,
+    pub minutes: u64,
+    pub seconds: u64,
+}
+
+impl DurationComponents {
+    /// Recombine the components into seconds, saturating at `u64::MAX`
+    ///
+    /// Example:
+    ///```
+    /// use compound_duration::components_wdhms;
+    ///
+    /// assert_eq!(components_wdhms(6_000_000).total_seconds(), 6_000_000);
+    ///```
+    #[must_use]
+    pub const fn total_seconds(&self) -> u64 {
+        let total = self.total();
+        if total > u64::MAX as u128 {
+            u64::MAX
+        } else {
+            total as u64
+        }
+    }
+
+    /// Total seconds, five `u64` fields always fit in a `u128`
+    const fn total(&self) -> u128 {
+        self.weeks as u128 * SECONDS_PER_WEEK as u128
+            + self.days as u128 * SECONDS_PER_DAY as u128
+            + self.hours as u128 * SECONDS_PER_HOUR as u128
+            + self.minutes as u128 * SECONDS_PER_MINUTE as u128
+            + self.seconds as u128
+    }
+
+    const fn fields(&self) -> [u64; 5] {
+        [
+            self.weeks,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+        ]
+    }
+}
+
+impl Ord for DurationComponents {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total()
+            .cmp(&other.total())
+            .then_with(|| self.fields().cmp(&other.fields()))
+    }
+}
+
+impl PartialOrd for DurationComponents {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Split seconds into weeks, days, hours, minutes and seconds
+#[must_use]
+pub const fn components_wdhms(seconds: u64) -> DurationComponents {
+    let (weeks, days, hours, minutes, seconds) = split_wdhms(seconds);
+    DurationComponents {
+        weeks,
+        days,
+        hours,
+        minutes,
+        seconds,
+    }
+}
+
+/// Split seconds into `(weeks, days, hours, minutes, seconds)`
+///
+/// Usable in `const` context.
+///
+/// Example:
+///```
+/// use compound_duration::split_wdhms;
+///
+/// const SPLIT: (u64, u64, u64, u64, u64) = split_wdhms(6_000_000);
+/// assert_eq!(SPLIT, (9, 6, 10, 40, 0));
+///```
+#[must_use]
+pub const fn split_wdhms(seconds: u64) -> (u64, u64, u64, u64, u64) {
+    let (week, day, hour, minute) = (
+        SECONDS_PER_WEEK as u64,
+        SECONDS_PER_DAY as u64,
+        SECONDS_PER_HOUR as u64,
+        SECONDS_PER_MINUTE as u64,
+    );
+    (
+        seconds / week,
+        seconds % week / day,
+        seconds % day / hour,
+        seconds % hour / minute,
+        seconds % minute,
+    )
+}
+
+/// Iterate over the non-zero units (week, days, hours, minutes, seconds) as
+/// `(value, suffix)` pairs, largest first
+///
+/// Zero yields a single `(0, "s")` pair. Nothing is allocated.
+///
+/// Example:
+///```
+/// use compound_duration::iter_wdhms;
+///
+/// let parts: Vec<_> = iter_wdhms(6_000_000).collect();
+/// assert_eq!(parts, [(9, "w"), (6, "d"), (10, "h"), (40, "m")]);
+///
+/// let short: String = iter_wdhms(6_000_000)
+///     .take(2)
+///     .map(|(value, suffix)| format!("{value}{suffix}"))
+///     .collect();
+/// assert_eq!(short, "9w6d");
+///```
+pub fn iter_wdhms(seconds: u64) -> impl Iterator<Item = (u64, &'static str)> {
+    let components = components_wdhms(seconds);
+    let parts = [
+        (components.weeks, "w"),
+        (components.days, "d"),
+        (components.hours, "h"),
+        (components.minutes, "m"),
+        (components.seconds, "s"),
+    ];
+    IntoIterator::into_iter(parts)
+        .filter(move |&(value, suffix)| value != 0 || (seconds == 0 && suffix == "s"))
+}
+
+/// Split nanoseconds into the non-zero units (days, hours, minutes, seconds,
+/// ms, µs, ns), largest first
+///
+/// The units are the same as in [`format_ns`](crate::format_ns), zero is a
+/// single `(0, Unit::Nano)` pair.
+///
+/// Example:
+///```
+/// use compound_duration::{parts_ns, Unit};
+///
+/// assert_eq!(
+///     parts_ns(3_000_129_723),
+///     [(3, Unit::Second), (129, Unit::Micro), (723, Unit::Nano)]
+/// );
+/// assert_eq!(parts_ns(0), [(0, Unit::Nano)]);
+///```
+#[must_use]
+pub fn parts_ns(nanos: u64) -> Vec<(u64, Unit)> {
+    if nanos == 0 {
+        return alloc::vec![(0, Unit::Nano)];
+    }
+
+    let units = [Unit::Day, Unit::Hour, Unit::Minute, Unit::Second];
+    let seconds = dhms_parts(nanos / NANOS_PER_SECOND as u64);
+    seconds
+        .iter()
+        .zip(units.iter())
+        .map(|(&(value, _), &unit)| (value, unit))
+        .chain(subsec_parts(nanos).iter().copied())
+        .filter(|(value, _)| *value != 0)
+        .collect()
+}
+
+/// Split seconds into the rendered `(number, suffix)` pieces of
+/// [`format_dhms`](crate::format_dhms)
+///
+/// Meant for styling the numbers and the suffixes separately, joining the
+/// pieces gives the `format_dhms` output. Zero is a single `("0", "s")` pair.
+///
+/// Example:
+///```
+/// use compound_duration::styled_parts_dhms;
+///
+/// let parts = styled_parts_dhms(6_000_000);
+/// assert_eq!(
+///     parts,
+///     [("69".to_string(), "d"), ("10".to_string(), "h"), ("40".to_string(), "m")]
+/// );
+/// for (number, suffix) in parts {
+///     print!("\x1b[1m{number}\x1b[0m{suffix}");
+/// }
+///```
+#[must_use]
+pub fn styled_parts_dhms<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+) -> Vec<(String, &'static str)>
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let seconds = to_u64(seconds);
+    if seconds == 0 {
+        return alloc::vec![(String::from("0"), "s")];
+    }
+
+    dhms_parts(seconds)
+        .iter()
+        .filter(|(value, _)| *value != 0)
+        .map(|(value, suffix)| (value.to_string(), *suffix))
+        .collect()
+}
+
+/// Convert seconds to a flat JSON object of the days, hours, minutes and
+/// seconds
+///
+/// Meant for structured logging, every key is always present, zeros
+/// included, so the schema never changes. The keys and values are fixed, so
+/// nothing needs escaping and no JSON library is used.
+///
+/// Example:
+///```
+/// use compound_duration::format_json_components;
+///
+/// assert_eq!(
+///     format_json_components(6_000_000),
+///     r#"{"days":69,"hours":10,"minutes":40,"seconds":0}"#
+/// );
+///```
+#[must_use]
+pub fn format_json_components<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let [(days, _), (hours, _), (minutes, _), (seconds, _)] = dhms_parts(to_u64(seconds));
+    format!(r#"{{"days":{days},"hours":{hours},"minutes":{minutes},"seconds":{seconds}}}"#)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        components_wdhms, format_json_components, iter_wdhms, parts_ns, split_wdhms,
+        styled_parts_dhms, DurationComponents,
+    };
+    use crate::{format_dhms, format_ns, format_wdhms, Unit};
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_components_wdhms() {
+        assert_eq!(components_wdhms(0), DurationComponents::default());
+        assert_eq!(
+            components_wdhms(61),
+            DurationComponents {
+                minutes: 1,
+                seconds: 1,
+                ..DurationComponents::default()
+            }
+        );
+        assert_eq!(
+            components_wdhms(4_294_967_295),
+            DurationComponents {
+                weeks: 7101,
+                days: 3,
+                hours: 6,
+                minutes: 28,
+                seconds: 15,
+            }
+        );
+        assert_eq!(components_wdhms(u64::MAX).weeks, 30_500_568_904_943);
+    }
+
+    #[test]
+    fn test_split_wdhms() {
+        const ZERO: (u64, u64, u64, u64, u64) = split_wdhms(0);
+        const SPLIT: (u64, u64, u64, u64, u64) = split_wdhms(4_294_967_295);
+        assert_eq!(ZERO, (0, 0, 0, 0, 0));
+        assert_eq!(SPLIT, (7101, 3, 6, 28, 15));
+        assert_eq!(split_wdhms(61), (0, 0, 0, 1, 1));
+        assert_eq!(split_wdhms(6_000_000), (9, 6, 10, 40, 0));
+        assert_eq!(split_wdhms(u64::MAX), (30_500_568_904_943, 0, 7, 0, 15));
+    }
+
+    #[test]
+    fn test_iter_wdhms() {
+        assert_eq!(iter_wdhms(0).collect::<Vec<_>>(), [(0, "s")]);
+        assert_eq!(iter_wdhms(1).collect::<Vec<_>>(), [(1, "s")]);
+        assert_eq!(iter_wdhms(60).collect::<Vec<_>>(), [(1, "m")]);
+        assert_eq!(iter_wdhms(86401).collect::<Vec<_>>(), [(1, "d"), (1, "s")]);
+        assert_eq!(
+            iter_wdhms(6_000_000).collect::<Vec<_>>(),
+            [(9, "w"), (6, "d"), (10, "h"), (40, "m")]
+        );
+        for seconds in (0..10_000_000).step_by(9973) {
+            let joined: String = iter_wdhms(seconds)
+                .map(|(value, suffix)| format!("{value}{suffix}"))
+                .collect();
+            assert_eq!(joined, format_wdhms(seconds));
+        }
+    }
+
+    #[test]
+    fn test_parts_ns() {
+        assert_eq!(parts_ns(0), [(0, Unit::Nano)]);
+        assert_eq!(parts_ns(1), [(1, Unit::Nano)]);
+        assert_eq!(parts_ns(1000), [(1, Unit::Micro)]);
+        assert_eq!(
+            parts_ns(3_000_129_723),
+            [(3, Unit::Second), (129, Unit::Micro), (723, Unit::Nano)]
+        );
+        assert_eq!(
+            parts_ns(100_000_000_010_100_001),
+            [
+                (1157, Unit::Day),
+                (9, Unit::Hour),
+                (46, Unit::Minute),
+                (40, Unit::Second),
+                (10, Unit::Milli),
+                (100, Unit::Micro),
+                (1, Unit::Nano)
+            ]
+        );
+        // days are never rolled into weeks
+        assert_eq!(parts_ns(604_800_000_000_000), [(7, Unit::Day)]);
+
+        for nanos in (0..997).map(|i| i * (u64::MAX / 997)) {
+            let joined: String = parts_ns(nanos)
+                .iter()
+                .map(|(value, unit)| format!("{value}{}", unit.suffix()))
+                .collect();
+            assert_eq!(joined, format_ns(nanos));
+        }
+    }
+
+    #[test]
+    fn test_styled_parts_dhms() {
+        let owned = |parts: &[(&str, &'static str)]| -> Vec<(String, &'static str)> {
+            parts
+                .iter()
+                .map(|&(number, suffix)| (String::from(number), suffix))
+                .collect()
+        };
+        assert_eq!(styled_parts_dhms(0), owned(&[("0", "s")]));
+        assert_eq!(styled_parts_dhms(0_u128), owned(&[("0", "s")]));
+        assert_eq!(styled_parts_dhms(1), owned(&[("1", "s")]));
+        assert_eq!(styled_parts_dhms(3600), owned(&[("1", "h")]));
+        assert_eq!(styled_parts_dhms(86_401), owned(&[("1", "d"), ("1", "s")]));
+        assert_eq!(
+            styled_parts_dhms(6_000_000),
+            owned(&[("69", "d"), ("10", "h"), ("40", "m")])
+        );
+        assert_eq!(
+            styled_parts_dhms(u64::MAX),
+            owned(&[("213503982334601", "d"), ("7", "h"), ("15", "s")])
+        );
+
+        for seconds in (0..997).map(|i| i * (u64::MAX / 997)).chain(0..10_000) {
+            let joined: String = styled_parts_dhms(seconds)
+                .iter()
+                .map(|(number, suffix)| format!("{number}{suffix}"))
+                .collect();
+            assert_eq!(joined, format_dhms(seconds));
+        }
+    }
+
+    #[test]
+    fn test_duration_components_total_seconds() {
+        assert_eq!(DurationComponents::default().total_seconds(), 0);
+        for seconds in (0..997)
+            .map(|i| i * (u64::MAX / 997))
+            .chain([u64::MAX, 6_000_000])
+        {
+            assert_eq!(components_wdhms(seconds).total_seconds(), seconds);
+        }
+        let unnormalized = DurationComponents {
+            days: 10,
+            minutes: 120,
+            ..DurationComponents::default()
+        };
+        assert_eq!(unnormalized.total_seconds(), 871_200);
+        let huge = DurationComponents {
+            weeks: u64::MAX,
+            ..DurationComponents::default()
+        };
+        assert_eq!(huge.total_seconds(), u64::MAX);
+    }
+
+    #[test]
+    fn test_duration_components_ord() {
+        let mut components: Vec<DurationComponents> = [6_000_000, 0, 86_400, 7200, 61, u64::MAX]
+            .iter()
+            .map(|&seconds| components_wdhms(seconds))
+            .collect();
+        components.sort();
+        let sorted: Vec<u64> = components
+            .iter()
+            .map(DurationComponents::total_seconds)
+            .collect();
+        assert_eq!(sorted, [0, 61, 7200, 86_400, 6_000_000, u64::MAX]);
+
+        // ordered by the total, not the fields
+        let eight_days = DurationComponents {
+            days: 8,
+            ..DurationComponents::default()
+        };
+        let week = components_wdhms(604_800);
+        assert!(week < eight_days);
+        assert!(components_wdhms(7200) < components_wdhms(86_400));
+
+        // equal totals are ordered by the fields to stay consistent with Eq
+        let seven_days = DurationComponents {
+            days: 7,
+            ..DurationComponents::default()
+        };
+        assert_ne!(week, seven_days);
+        assert!(seven_days < week);
+        assert_eq!(week.cmp(&week), core::cmp::Ordering::Equal);
+        let saturated = DurationComponents {
+            weeks: u64::MAX,
+            ..DurationComponents::default()
+        };
+        assert!(components_wdhms(u64::MAX) < saturated);
+
+        let mut set = alloc::collections::BTreeSet::new();
+        assert!(set.insert(week));
+        assert!(set.insert(seven_days));
+        assert!(!set.insert(components_wdhms(604_800)));
+    }
+
+    #[test]
+    fn test_format_json_components() {
+        assert_eq!(
+            format_json_components(0),
+            r#"{"days":0,"hours":0,"minutes":0,"seconds":0}"#
+        );
+        assert_eq!(
+            format_json_components(61),
+            r#"{"days":0,"hours":0,"minutes":1,"seconds":1}"#
+        );
+        assert_eq!(
+            format_json_components(6_000_000),
+            r#"{"days":69,"hours":10,"minutes":40,"seconds":0}"#
+        );
+        assert_eq!(
+            format_json_components(u64::MAX),
+            r#"{"days":213503982334601,"hours":7,"minutes":0,"seconds":15}"#
+        );
+
+        for seconds in (0..997).map(|i| i * (u64::MAX / 997)).chain([6_000_000]) {
+            let json: serde_json::Value =
+                serde_json::from_str(&format_json_components(seconds)).unwrap();
+            let object = json.as_object().unwrap();
+            assert_eq!(object.len(), 4);
+            let field = |key: &str| object[key].as_u64().unwrap();
+            assert_eq!(
+                field("days") * 86_400
+                    + field("hours") * 3600
+                    + field("minutes") * 60
+                    + field("seconds"),
+                seconds
+            );
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{dhms_parts, to_u64, NANOS_PER_SECOND};
+use alloc::string::String;
+use core::convert::{TryFrom, TryInto};
+use core::fmt::{Debug, Write};
+use core::ops::BitAnd;
+
+/// Write `nanos` with the seconds and everything below as a decimal, trailing
+/// zeros of the fraction are removed when `trim` is `true`
+fn write_decimal(out: &mut String, nanos: u64, trim: bool) {
+    let (seconds, fraction) = (
+        nanos / NANOS_PER_SECOND as u64,
+        nanos % NANOS_PER_SECOND as u64,
+    );
+    let [days, hours, minutes, (secs, _)] = dhms_parts(seconds);
+
+    // writing to a String never fails
+    for (value, suffix) in [days, hours, minutes]
+        .iter()
+        .filter(|(value, _)| *value != 0)
+    {
+        write!(out, "{value}{suffix}").unwrap();
+    }
+
+    if fraction != 0 {
+        let mut digits = String::new();
+        write!(digits, "{fraction:09}").unwrap();
+        let digits = if trim {
+            digits.trim_end_matches('0')
+        } else {
+            &digits
+        };
+        write!(out, "{secs}.{digits}s").unwrap();
+    } else if secs != 0 || out.is_empty() {
+        write!(out, "{secs}s").unwrap();
+    }
+}
+
+/// Convert nanoseconds to compound duration (days, hours, minutes) with the
+/// seconds as a decimal
+///
+/// Seconds, ms, µs and ns are collapsed into a single seconds field with
+/// exactly 9 fractional digits. Whole seconds have no fraction at all, so
+/// `"40s"` instead of `"40.000000000s"`, and zero is `"0s"`. See
+/// [`format_ns_decimal_trimmed`] to drop the trailing zeros.
+///
+/// The output is lossless, the leading zeros of the fraction are always kept,
+/// so [`parse_ns`](crate::parse_ns) gives back `nanos` for every `u64`.
+///
+/// Example:
+///```
+/// use compound_duration::format_ns_decimal;
+///
+/// assert_eq!(
+///     format_ns_decimal(100_000_000_010_100_001_u64),
+///     "1157d9h46m40.010100001s"
+/// );
+/// assert_eq!(format_ns_decimal(3_129_723_000_u64), "3.129723000s");
+/// assert_eq!(format_ns_decimal(40_000_000_000_u64), "40s");
+///```
+#[must_use]
+pub fn format_ns_decimal<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(nanos: T) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let mut compound_duration = String::new();
+    write_decimal(&mut compound_duration, to_u64(nanos), false);
+    compound_duration
+}
+
+/// Convert nanoseconds to compound duration (days, hours, minutes) with the
+/// seconds as a decimal without trailing zeros
+///
+/// Same as [`format_ns_decimal`] but the fraction only keeps the digits it
+/// needs, `"3.129723s"` instead of `"3.129723000s"`. Only trailing zeros are
+/// dropped, so it round-trips through [`parse_ns`](crate::parse_ns) as well.
+///
+/// Example:
+///```
+/// use compound_duration::format_ns_decimal_trimmed;
+///
+/// assert_eq!(format_ns_decimal_trimmed(3_129_723_000_u64), "3.129723s");
+/// assert_eq!(format_ns_decimal_trimmed(1_500_000_000_u64), "1.5s");
+///```
+#[must_use]
+pub fn format_ns_decimal_trimmed<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    nanos: T,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let mut compound_duration = String::new();
+    write_decimal(&mut compound_duration, to_u64(nanos), true);
+    compound_duration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_ns_decimal, format_ns_decimal_trimmed};
+
+    #[test]
+    fn test_format_ns_decimal() {
+        assert_eq!(format_ns_decimal(0), "0s");
+        assert_eq!(format_ns_decimal(1), "0.000000001s");
+        assert_eq!(format_ns_decimal(500_000_000), "0.500000000s");
+        assert_eq!(format_ns_decimal(3_129_723_000_u64), "3.129723000s");
+        assert_eq!(
+            format_ns_decimal(100_000_000_010_100_001_u64),
+            "1157d9h46m40.010100001s"
+        );
+
+        // whole seconds have no fraction
+        assert_eq!(format_ns_decimal(1_000_000_000), "1s");
+        assert_eq!(format_ns_decimal(40_000_000_000_u64), "40s");
+        assert_eq!(format_ns_decimal(60_000_000_000_u64), "1m");
+        assert_eq!(
+            format_ns_decimal(100_000_000_000_000_000_u64),
+            "1157d9h46m40s"
+        );
+
+        // no seconds but a fraction
+        assert_eq!(format_ns_decimal(60_000_000_001_u64), "1m0.000000001s");
+        assert_eq!(format_ns_decimal(u64::MAX), "213503d23h34m33.709551615s");
+    }
+
+    #[test]
+    fn test_format_ns_decimal_trimmed() {
+        assert_eq!(format_ns_decimal_trimmed(0), "0s");
+        assert_eq!(format_ns_decimal_trimmed(1), "0.000000001s");
+        assert_eq!(format_ns_decimal_trimmed(500_000_000), "0.5s");
+        assert_eq!(format_ns_decimal_trimmed(1_500_000_000), "1.5s");
+        assert_eq!(format_ns_decimal_trimmed(3_129_723_000_u64), "3.129723s");
+        assert_eq!(format_ns_decimal_trimmed(40_000_000_000_u64), "40s");
+        assert_eq!(format_ns_decimal_trimmed(60_010_000_000_u64), "1m0.01s");
+        assert_eq!(
+            format_ns_decimal_trimmed(100_000_000_010_100_001_u64),
+            "1157d9h46m40.010100001s"
+        );
+    }
+}
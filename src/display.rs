@@ -0,0 +1,96 @@
+use crate::buf::{StackBuf, MAX_DHMS_LONG_LEN};
+use crate::{write_dhms, write_dhms_long};
+use core::fmt;
+
+/// Seconds that display as compound duration (days, hours, minutes, seconds)
+///
+/// Renders the same output as [`format_dhms`](crate::format_dhms) without
+/// allocating, the alternate flag `{:#}` switches to the long form of
+/// [`format_dhms_long`](crate::format_dhms_long). Width, fill and alignment
+/// are honored like for a `&str`.
+///
+/// Example:
+///```
+/// use compound_duration::Dhms;
+///
+/// assert_eq!(format!("{}", Dhms(6_000_000)), "69d10h40m");
+/// assert_eq!(format!("{:#}", Dhms(6_000_000)), "69 days 10 hours 40 minutes");
+/// assert_eq!(format!("[{:>8}]", Dhms(61)), "[    1m1s]");
+///```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dhms(pub u64);
+
+impl Dhms {
+    fn write<W: fmt::Write>(self, out: &mut W, long: bool) -> fmt::Result {
+        if long {
+            write_dhms_long(out, self.0)
+        } else {
+            write_dhms(out, self.0)
+        }
+    }
+}
+
+impl fmt::Display for Dhms {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let long = f.alternate();
+        if f.width().is_none() {
+            return self.write(f, long);
+        }
+        let mut buf = StackBuf::<MAX_DHMS_LONG_LEN>::new();
+        // the buffer fits the longest output
+        self.write(&mut buf, long).unwrap();
+        f.pad(buf.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dhms;
+    use crate::buf::MAX_DHMS_LONG_LEN;
+    use alloc::format;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_dhms_display() {
+        assert_eq!(Dhms(0).to_string(), "0s");
+        assert_eq!(Dhms(61).to_string(), "1m1s");
+        assert_eq!(Dhms(6_000_000).to_string(), "69d10h40m");
+        assert_eq!(Dhms(u64::MAX).to_string(), "213503982334601d7h15s");
+        assert_eq!(format!("up {} now", Dhms(3600)), "up 1h now");
+
+        assert_eq!(format!("{:#}", Dhms(0)), "0 seconds");
+        assert_eq!(format!("{:#}", Dhms(61)), "1 minute 1 second");
+        assert_eq!(
+            format!("{:#}", Dhms(6_000_000)),
+            "69 days 10 hours 40 minutes"
+        );
+    }
+
+    #[test]
+    fn test_dhms_display_padding() {
+        assert_eq!(format!("[{:>12}]", Dhms(61)), "[        1m1s]");
+        assert_eq!(format!("[{:<12}]", Dhms(61)), "[1m1s        ]");
+        assert_eq!(format!("[{:^12}]", Dhms(61)), "[    1m1s    ]");
+        assert_eq!(format!("[{:*^11}]", Dhms(61)), "[***1m1s****]");
+        assert_eq!(format!("[{:>2}]", Dhms(61)), "[1m1s]");
+        assert_eq!(format!("[{:>12}]", Dhms(0)), "[          0s]");
+
+        assert_eq!(format!("[{:>#20}]", Dhms(61)), "[   1 minute 1 second]");
+        assert_eq!(format!("[{:<#20}]", Dhms(61)), "[1 minute 1 second   ]");
+        assert_eq!(format!("[{:^#20}]", Dhms(61)), "[ 1 minute 1 second  ]");
+        assert_eq!(format!("[{:>#1$}]", Dhms(3600), 8), "[  1 hour]");
+    }
+
+    #[test]
+    fn test_dhms_display_longest() {
+        let longest = (u64::MAX / 86_400 - 1) * 86_400 + 86_399;
+        assert_eq!(format!("{:#}", Dhms(longest)).len(), MAX_DHMS_LONG_LEN);
+        for seconds in [longest, u64::MAX] {
+            assert_eq!(format!("{:1}", Dhms(seconds)), Dhms(seconds).to_string());
+            assert_eq!(
+                format!("{:#1}", Dhms(seconds)),
+                format!("{:#}", Dhms(seconds))
+            );
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use crate::{parse_dhms, ParseError};
+use core::str::FromStr;
+
+/// A duration in seconds that can be parsed from its compound form
+///
+/// Example:
+///```
+/// use compound_duration::CompoundDuration;
+///
+/// let duration: CompoundDuration = "1d2h".parse().unwrap();
+/// assert_eq!(duration.as_secs(), 93_600);
+///```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CompoundDuration(u64);
+
+impl CompoundDuration {
+    /// Create a new `CompoundDuration` from seconds
+    #[must_use]
+    pub const fn from_secs(seconds: u64) -> Self {
+        Self(seconds)
+    }
+
+    /// Return the number of seconds
+    #[must_use]
+    pub const fn as_secs(self) -> u64 {
+        self.0
+    }
+
+    /// Return `true` if the duration is zero seconds long
+    ///
+    /// Example:
+    ///```
+    /// use compound_duration::CompoundDuration;
+    ///
+    /// let minute = CompoundDuration::from_secs(60);
+    /// assert!(minute.saturating_sub(minute).is_zero());
+    /// assert!(!minute.is_zero());
+    ///```
+    #[must_use]
+    pub const fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Add two durations, returning `None` on overflow
+    ///
+    /// Example:
+    ///```
+    /// use compound_duration::CompoundDuration;
+    ///
+    /// let hour = CompoundDuration::from_secs(3600);
+    /// assert_eq!(hour.checked_add(hour), Some(CompoundDuration::from_secs(7200)));
+    /// assert_eq!(CompoundDuration::from_secs(u64::MAX).checked_add(hour), None);
+    ///```
+    #[must_use]
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(seconds) => Some(Self(seconds)),
+            None => None,
+        }
+    }
+
+    /// Subtract `other` from this duration, returning `None` if `other` is
+    /// longer
+    ///
+    /// Example:
+    ///```
+    /// use compound_duration::CompoundDuration;
+    ///
+    /// let (hour, minute) = (CompoundDuration::from_secs(3600), CompoundDuration::from_secs(60));
+    /// assert_eq!(hour.checked_sub(minute), Some(CompoundDuration::from_secs(3540)));
+    /// assert_eq!(minute.checked_sub(hour), None);
+    ///```
+    #[must_use]
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.0.checked_sub(other.0) {
+            Some(seconds) => Some(Self(seconds)),
+            None => None,
+        }
+    }
+
+    /// Add two durations, saturating at `u64::MAX` seconds
+    #[must_use]
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    /// Subtract `other` from this duration, saturating at zero
+    #[must_use]
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+}
+
+impl FromStr for CompoundDuration {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_dhms(s).map(Self)
+    }
+}
+
+impl From<u64> for CompoundDuration {
+    fn from(seconds: u64) -> Self {
+        Self(seconds)
+    }
+}
+
+impl From<CompoundDuration> for u64 {
+    fn from(duration: CompoundDuration) -> Self {
+        duration.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for CompoundDuration {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&crate::format_dhms(self.0))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for CompoundDuration {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl ::serde::de::Visitor<'_> for Visitor {
+            type Value = CompoundDuration;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("a compound duration like \"1d2h\"")
+            }
+
+            fn visit_str<E: ::serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse()
+                    .map_err(|e| E::custom(format_args!("invalid duration {v:?}: {e}")))
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CompoundDuration;
+    use crate::ParseError;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("0s".parse(), Ok(CompoundDuration(0)));
+        assert_eq!("1d2h".parse(), Ok(CompoundDuration(93_600)));
+        assert_eq!(
+            "69d10h40m"
+                .parse::<CompoundDuration>()
+                .map(CompoundDuration::as_secs),
+            Ok(6_000_000)
+        );
+        assert_eq!(
+            "2h1d".parse::<CompoundDuration>(),
+            Err(ParseError::OutOfOrder(3))
+        );
+        assert!(CompoundDuration(59) < CompoundDuration::from_secs(60));
+        assert_eq!(u64::from(CompoundDuration::from(7259)), 7259);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let zero = CompoundDuration::default();
+        let hour = CompoundDuration::from_secs(3600);
+        let minute = CompoundDuration::from_secs(60);
+        let max = CompoundDuration::from_secs(u64::MAX);
+
+        assert_eq!(hour.checked_add(minute), Some(CompoundDuration(3660)));
+        assert_eq!(hour.checked_add(zero), Some(hour));
+        assert_eq!(max.checked_add(zero), Some(max));
+        assert_eq!(max.checked_add(minute), None);
+
+        assert_eq!(hour.checked_sub(minute), Some(CompoundDuration(3540)));
+        assert_eq!(hour.checked_sub(hour), Some(zero));
+        assert_eq!(minute.checked_sub(hour), None);
+        assert_eq!(zero.checked_sub(minute), None);
+
+        assert_eq!(hour.saturating_add(minute), CompoundDuration(3660));
+        assert_eq!(max.saturating_add(minute), max);
+        assert_eq!(hour.saturating_sub(minute), CompoundDuration(3540));
+        assert_eq!(minute.saturating_sub(hour), zero);
+    }
+
+    #[test]
+    fn test_is_zero() {
+        let minute = CompoundDuration::from_secs(60);
+        assert!(CompoundDuration::default().is_zero());
+        assert!(CompoundDuration::from_secs(0).is_zero());
+        assert!(!CompoundDuration::from_secs(1).is_zero());
+        assert!(!CompoundDuration::from_secs(u64::MAX).is_zero());
+        assert!(minute.saturating_sub(minute).is_zero());
+        assert!(minute.checked_sub(minute).unwrap().is_zero());
+        assert!(CompoundDuration::from_secs(30)
+            .saturating_sub(minute)
+            .is_zero());
+        assert!("0s".parse::<CompoundDuration>().unwrap().is_zero());
+        assert!(!"1s".parse::<CompoundDuration>().unwrap().is_zero());
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::CompoundDuration;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_serde() {
+        let duration = CompoundDuration::from_secs(93_600);
+        let json = serde_json::to_string(&duration).unwrap();
+        assert_eq!(json, r#""1d2h""#);
+        assert_eq!(
+            serde_json::from_str::<CompoundDuration>(&json).unwrap(),
+            duration
+        );
+        assert_eq!(
+            serde_json::from_str::<Vec<CompoundDuration>>(r#"["0s","59s","1h"]"#).unwrap(),
+            vec![
+                CompoundDuration::from_secs(0),
+                CompoundDuration::from_secs(59),
+                CompoundDuration::from_secs(3600)
+            ]
+        );
+        assert!(serde_json::from_str::<CompoundDuration>(r#""1x""#).is_err());
+        assert!(serde_json::from_str::<CompoundDuration>("3600").is_err());
+
+        let err = serde_json::from_str::<CompoundDuration>(r#""2h1d""#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid duration "2h1d": unit out of order at byte 3 at line 1 column 6"#
+        );
+    }
+}
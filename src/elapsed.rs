@@ -0,0 +1,76 @@
+use crate::{format_duration, format_duration_dhms};
+use alloc::string::String;
+use std::time::Instant;
+
+/// Convert the time elapsed since `since` to compound duration (days, hours,
+/// minutes, seconds, ms, µs, ns)
+///
+/// Same as [`format_duration`] of `since.elapsed()`, nothing is truncated
+/// unlike casting `as_nanos()` to a `u64`.
+///
+/// Example:
+///```
+/// use compound_duration::format_elapsed;
+/// use std::time::Instant;
+///
+/// let now = Instant::now();
+/// // do something ...
+/// println!("{}", format_elapsed(now));
+///```
+#[must_use]
+pub fn format_elapsed(since: Instant) -> String {
+    format_duration(since.elapsed())
+}
+
+/// Convert the time elapsed since `since` to compound duration (days, hours,
+/// minutes, seconds)
+///
+/// Same as [`format_duration_dhms`] of `since.elapsed()`, the fractional part
+/// of a second is discarded.
+///
+/// Example:
+///```
+/// use compound_duration::format_elapsed_dhms;
+/// use std::time::Instant;
+///
+/// let now = Instant::now();
+/// // do something ...
+/// println!("{}", format_elapsed_dhms(now));
+///```
+#[must_use]
+pub fn format_elapsed_dhms(since: Instant) -> String {
+    format_duration_dhms(since.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_elapsed, format_elapsed_dhms};
+    use crate::{parse_dhms, parse_ns};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_format_elapsed() {
+        let now = Instant::now();
+        let elapsed = parse_ns(&format_elapsed(now)).unwrap();
+        assert!(Duration::from_nanos(elapsed) <= now.elapsed());
+
+        let Some(earlier) = Instant::now().checked_sub(Duration::from_secs(61)) else {
+            return;
+        };
+        let elapsed = parse_ns(&format_elapsed(earlier)).unwrap();
+        assert!(elapsed >= 61_000_000_000);
+        assert!(Duration::from_nanos(elapsed) <= earlier.elapsed());
+    }
+
+    #[test]
+    fn test_format_elapsed_dhms() {
+        assert_eq!(format_elapsed_dhms(Instant::now()), "0s");
+
+        let Some(earlier) = Instant::now().checked_sub(Duration::from_secs(3661)) else {
+            return;
+        };
+        let elapsed = parse_dhms(&format_elapsed_dhms(earlier)).unwrap();
+        assert!(elapsed >= 3661);
+        assert!(elapsed <= earlier.elapsed().as_secs());
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{
+    format_dhms, format_dhms_u128, format_duration, format_duration_dhms, format_ns,
+    format_ns_u128, format_wdhms, format_wdhms_u128,
+};
+use alloc::string::String;
+use core::time::Duration;
+
+/// Method call style for the compound duration functions
+///
+/// Integers are seconds for [`dhms`](Self::dhms) and
+/// [`wdhms`](Self::wdhms) and nanoseconds for [`ns`](Self::ns), the same as
+/// [`format_dhms`], [`format_wdhms`] and [`format_ns`] that every method
+/// delegates to. `u128` is formatted in full and a [`Duration`] uses its own
+/// seconds and nanoseconds.
+///
+/// Example:
+///```
+/// use compound_duration::DurationFormat;
+/// use std::time::Duration;
+///
+/// assert_eq!(6_000_000_u64.dhms(), "69d10h40m");
+/// assert_eq!(6_000_000_u32.wdhms(), "9w6d10h40m");
+/// assert_eq!(3_000_129_723_u64.ns(), "3s129µs723ns");
+/// assert_eq!(Duration::from_millis(61_500).dhms(), "1m1s");
+/// assert_eq!(Duration::from_millis(61_500).ns(), "1m1s500ms");
+///```
+pub trait DurationFormat {
+    /// Format as compound duration (days, hours, minutes, seconds)
+    #[must_use]
+    fn dhms(self) -> String;
+
+    /// Format as compound duration (week, days, hours, minutes, seconds)
+    #[must_use]
+    fn wdhms(self) -> String;
+
+    /// Format as compound duration (days, hours, minutes, seconds, ms, µs, ns)
+    #[must_use]
+    fn ns(self) -> String;
+}
+
+macro_rules! impl_duration_format {
+    ($($ty:ty),+) => {$(
+        impl DurationFormat for $ty {
+            fn dhms(self) -> String {
+                format_dhms(self)
+            }
+
+            fn wdhms(self) -> String {
+                format_wdhms(self)
+            }
+
+            fn ns(self) -> String {
+                format_ns(self)
+            }
+        }
+    )+};
+}
+
+impl_duration_format!(u8, u16, u32, u64, usize);
+
+impl DurationFormat for u128 {
+    fn dhms(self) -> String {
+        format_dhms_u128(self)
+    }
+
+    fn wdhms(self) -> String {
+        format_wdhms_u128(self)
+    }
+
+    fn ns(self) -> String {
+        format_ns_u128(self)
+    }
+}
+
+impl DurationFormat for Duration {
+    fn dhms(self) -> String {
+        format_duration_dhms(self)
+    }
+
+    fn wdhms(self) -> String {
+        format_wdhms(self.as_secs())
+    }
+
+    fn ns(self) -> String {
+        format_duration(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DurationFormat;
+    use crate::{format_dhms, format_ns, format_wdhms};
+    use core::time::Duration;
+
+    #[test]
+    fn test_duration_format() {
+        assert_eq!(0_u64.dhms(), "0s");
+        assert_eq!(200_u8.dhms(), "3m20s");
+        assert_eq!(50_000_u16.dhms(), "13h53m20s");
+        assert_eq!(6_000_000_u32.dhms(), "69d10h40m");
+        assert_eq!(6_000_000_u64.dhms(), "69d10h40m");
+        assert_eq!(6_000_000_usize.wdhms(), "9w6d10h40m");
+        assert_eq!(3_000_129_723_u64.ns(), "3s129\u{b5}s723ns");
+
+        let wide = u128::from(u64::MAX) + 1;
+        assert_eq!(wide.dhms(), "213503982334601d7h16s");
+        assert_eq!(wide.wdhms(), "30500568904943w7h16s");
+        assert_eq!(wide.ns(), "213503d23h34m33s709ms551\u{b5}s616ns");
+
+        let duration = Duration::new(6_000_000, 1_000_001);
+        assert_eq!(duration.dhms(), "69d10h40m");
+        assert_eq!(duration.wdhms(), "9w6d10h40m");
+        assert_eq!(duration.ns(), "69d10h40m1ms1ns");
+        assert_eq!(Duration::ZERO.ns(), "0ns");
+
+        for value in (0..997).map(|i| i * (u64::MAX / 997)) {
+            assert_eq!(value.dhms(), format_dhms(value));
+            assert_eq!(value.wdhms(), format_wdhms(value));
+            assert_eq!(value.ns(), format_ns(value));
+            assert_eq!(u128::from(value).dhms(), format_dhms(value));
+            assert_eq!(Duration::from_nanos(value).ns(), format_ns(value));
+        }
+    }
+}
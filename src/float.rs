@@ -0,0 +1,121 @@
+use crate::{write_ns, ConversionError, NANOS_PER_SECOND};
+use alloc::string::String;
+
+/// Convert `secs` to whole nanoseconds, rounded to the nearest nanosecond
+///
+/// The whole and fractional seconds are converted separately so that values
+/// like `3.129723` don't end up one nanosecond short. Returns `None` for NaN,
+/// infinite, negative or too large values.
+fn secs_f64_to_nanos(secs: f64) -> Option<u64> {
+    if !secs.is_finite() || secs < 0.0 || secs >= u64::MAX as f64 {
+        return None;
+    }
+
+    let whole = secs as u64;
+    let fraction = secs - whole as f64;
+    let fraction = (fraction * NANOS_PER_SECOND as f64 + 0.5) as u64;
+
+    whole
+        .checked_mul(NANOS_PER_SECOND as u64)
+        .and_then(|nanos| nanos.checked_add(fraction))
+}
+
+/// Convert seconds as `f64` to compound duration (days, hours, minutes,
+/// seconds, ms, µs, ns)
+///
+/// The value is rounded to the nearest nanosecond and formatted like
+/// [`format_ns`](crate::format_ns). Out of range input saturates the same way
+/// an `as` cast does: NaN and negative values are `"0ns"`, infinity and
+/// anything above `u64::MAX` nanoseconds are formatted as `u64::MAX`
+/// nanoseconds. Use [`try_format_secs_f64`] to reject them instead.
+///
+/// Example:
+///```
+/// use compound_duration::format_secs_f64;
+///
+/// assert_eq!(format_secs_f64(3.129723), "3s129ms723µs");
+/// assert_eq!(format_secs_f64(0.5), "500ms");
+/// assert_eq!(format_secs_f64(f64::NAN), "0ns");
+///```
+#[must_use]
+pub fn format_secs_f64(secs: f64) -> String {
+    let nanos = secs_f64_to_nanos(secs).unwrap_or(if secs > 0.0 { u64::MAX } else { 0 });
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_ns(&mut compound_duration, nanos).unwrap();
+    compound_duration
+}
+
+/// Convert seconds as `f64` to compound duration (days, hours, minutes,
+/// seconds, ms, µs, ns), rejecting values that can't be represented
+///
+/// # Errors
+///
+/// Returns a [`ConversionError`] if `secs` is NaN, infinite, negative or
+/// larger than `u64::MAX` nanoseconds.
+///
+/// Example:
+///```
+/// use compound_duration::{try_format_secs_f64, ConversionError};
+///
+/// assert_eq!(try_format_secs_f64(3.129723), Ok(String::from("3s129ms723µs")));
+/// assert_eq!(try_format_secs_f64(-1.0), Err(ConversionError));
+/// assert_eq!(try_format_secs_f64(f64::INFINITY), Err(ConversionError));
+///```
+pub fn try_format_secs_f64(secs: f64) -> Result<String, ConversionError> {
+    let nanos = secs_f64_to_nanos(secs).ok_or(ConversionError)?;
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_ns(&mut compound_duration, nanos).unwrap();
+    Ok(compound_duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_secs_f64, try_format_secs_f64};
+    use crate::{format_ns, ConversionError};
+
+    #[test]
+    fn test_format_secs_f64() {
+        assert_eq!(format_secs_f64(0.0), "0ns");
+        assert_eq!(format_secs_f64(-0.0), "0ns");
+        assert_eq!(format_secs_f64(1e-9), "1ns");
+        assert_eq!(format_secs_f64(0.4e-9), "0ns");
+        assert_eq!(format_secs_f64(0.6e-9), "1ns");
+        assert_eq!(format_secs_f64(0.001), "1ms");
+        assert_eq!(format_secs_f64(0.5), "500ms");
+        assert_eq!(format_secs_f64(1.0), "1s");
+        assert_eq!(format_secs_f64(3.129723), "3s129ms723\u{b5}s");
+        assert_eq!(format_secs_f64(0.3), "300ms");
+        assert_eq!(format_secs_f64(59.999_999_999_9), "1m");
+        assert_eq!(format_secs_f64(6_000_000.0), "69d10h40m");
+        assert_eq!(format_secs_f64(86_400.25), "1d250ms");
+
+        // saturates like an `as` cast
+        assert_eq!(format_secs_f64(-1.0), "0ns");
+        assert_eq!(format_secs_f64(f64::NAN), "0ns");
+        assert_eq!(format_secs_f64(f64::NEG_INFINITY), "0ns");
+        assert_eq!(format_secs_f64(f64::INFINITY), format_ns(u64::MAX));
+        assert_eq!(format_secs_f64(1e20), format_ns(u64::MAX));
+    }
+
+    #[test]
+    fn test_try_format_secs_f64() {
+        assert_eq!(try_format_secs_f64(0.0).as_deref(), Ok("0ns"));
+        assert_eq!(
+            try_format_secs_f64(3.129723).as_deref(),
+            Ok("3s129ms723\u{b5}s")
+        );
+        assert_eq!(
+            try_format_secs_f64(18_446_744_073.0).as_deref(),
+            Ok("213503d23h34m33s")
+        );
+
+        assert_eq!(try_format_secs_f64(-1.0), Err(ConversionError));
+        assert_eq!(try_format_secs_f64(-1e-9), Err(ConversionError));
+        assert_eq!(try_format_secs_f64(f64::NAN), Err(ConversionError));
+        assert_eq!(try_format_secs_f64(f64::INFINITY), Err(ConversionError));
+        assert_eq!(try_format_secs_f64(f64::NEG_INFINITY), Err(ConversionError));
+        assert_eq!(try_format_secs_f64(18_446_744_074.0), Err(ConversionError));
+    }
+}
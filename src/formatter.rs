@@ -0,0 +1,545 @@
+use crate::truncate::round_wide;
+use crate::{RoundMode, Unit, NANOS_PER_SECOND};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Built-in units, largest first, as `(suffix, nanoseconds)`
+const BUILTIN: [(&str, u128); 8] = {
+    let mut units = [("", 0); 8];
+    let mut i = 0;
+    while i < units.len() {
+        let unit = Unit::ALL[i];
+        units[i] = (unit.suffix(), unit.in_nanos() as u128);
+        i += 1;
+    }
+    units
+};
+
+/// Order the units are written in by [`DurationFormatter`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// Largest unit first, `"69d10h40m"`
+    #[default]
+    LargestFirst,
+    /// Smallest unit first, `"40m10h69d"`
+    SmallestFirst,
+}
+
+const WEEKS: usize = 0;
+const DAYS: usize = 1;
+const HOURS: usize = 2;
+const MINUTES: usize = 3;
+const SECONDS: usize = 4;
+const MILLIS: usize = 5;
+const MICROS: usize = 6;
+const NANOSECONDS: usize = 7;
+
+/// Builder to format durations with a custom set of units
+///
+/// By default days, hours, minutes and seconds are used, the same as
+/// [`format_dhms`](crate::format_dhms). The total is allocated greedily from
+/// the largest to the smallest enabled unit and whatever is left below the
+/// smallest unit is discarded, unless [`rounding`](Self::rounding) is set.
+/// Zero is rendered as `0` followed by the smallest enabled unit.
+///
+/// Example:
+///```
+/// use compound_duration::DurationFormatter;
+///
+/// let formatter = DurationFormatter::new()
+///     .with_weeks(true)
+///     .with_unit("fn", 14 * 86_400)
+///     .separator(" ");
+/// assert_eq!(formatter.format(6_000_000), "4fn 1w 6d 10h 40m");
+///```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DurationFormatter<'a> {
+    builtin: [bool; 8],
+    custom: Vec<(&'a str, u128)>,
+    separator: &'a str,
+    ascii: bool,
+    rounding: RoundMode,
+    order: Order,
+}
+
+impl Default for DurationFormatter<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> DurationFormatter<'a> {
+    /// Create a formatter using days, hours, minutes and seconds
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            builtin: [false, true, true, true, true, false, false, false],
+            custom: Vec::new(),
+            separator: "",
+            ascii: false,
+            rounding: RoundMode::Floor,
+            order: Order::LargestFirst,
+        }
+    }
+
+    /// Enable or disable weeks (`w`)
+    #[must_use]
+    pub const fn with_weeks(self, enabled: bool) -> Self {
+        self.with_builtin(WEEKS, enabled)
+    }
+
+    /// Enable or disable days (`d`)
+    #[must_use]
+    pub const fn with_days(self, enabled: bool) -> Self {
+        self.with_builtin(DAYS, enabled)
+    }
+
+    /// Enable or disable hours (`h`)
+    #[must_use]
+    pub const fn with_hours(self, enabled: bool) -> Self {
+        self.with_builtin(HOURS, enabled)
+    }
+
+    /// Enable or disable minutes (`m`)
+    #[must_use]
+    pub const fn with_minutes(self, enabled: bool) -> Self {
+        self.with_builtin(MINUTES, enabled)
+    }
+
+    /// Enable or disable seconds (`s`)
+    #[must_use]
+    pub const fn with_seconds(self, enabled: bool) -> Self {
+        self.with_builtin(SECONDS, enabled)
+    }
+
+    /// Enable or disable milliseconds (`ms`)
+    #[must_use]
+    pub const fn with_millis(self, enabled: bool) -> Self {
+        self.with_builtin(MILLIS, enabled)
+    }
+
+    /// Enable or disable microseconds (`µs`)
+    #[must_use]
+    pub const fn with_micros(self, enabled: bool) -> Self {
+        self.with_builtin(MICROS, enabled)
+    }
+
+    /// Enable or disable nanoseconds (`ns`)
+    #[must_use]
+    pub const fn with_nanos(self, enabled: bool) -> Self {
+        self.with_builtin(NANOSECONDS, enabled)
+    }
+
+    const fn with_builtin(mut self, index: usize, enabled: bool) -> Self {
+        self.builtin[index] = enabled;
+        self
+    }
+
+    /// Add a custom unit of `seconds_per_unit` seconds rendered with `label`
+    ///
+    /// Custom and built-in units are allocated together from the largest to
+    /// the smallest, so a fortnight lands between weeks and days. When two
+    /// units have the same size the built-in unit goes first, then custom
+    /// units in the order they were added, the later ones are always zero.
+    ///
+    /// Example:
+    ///```
+    /// use compound_duration::DurationFormatter;
+    ///
+    /// let formatter = DurationFormatter::new()
+    ///     .with_weeks(true)
+    ///     .with_unit("fn", 14 * 86_400)
+    ///     .with_unit("dec", 10 * 365 * 86_400);
+    /// assert_eq!(formatter.format(400_000_000), "1dec69fn1w6d15h6m40s");
+    ///```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `seconds_per_unit` is zero.
+    #[must_use]
+    pub fn with_unit(mut self, label: &'a str, seconds_per_unit: u64) -> Self {
+        assert!(seconds_per_unit != 0, "a unit can't be zero seconds long");
+        let size = u128::from(seconds_per_unit) * NANOS_PER_SECOND as u128;
+        // keep the custom units sorted, largest first
+        let index = self
+            .custom
+            .iter()
+            .position(|(_, other)| *other < size)
+            .unwrap_or(self.custom.len());
+        self.custom.insert(index, (label, size));
+        self
+    }
+
+    /// Set the separator written between units, empty by default
+    #[must_use]
+    pub const fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Write microseconds as `us` instead of `µs` when `ascii` is `true`
+    #[must_use]
+    pub const fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    /// Round the remainder below the smallest enabled unit with `mode`,
+    /// [`RoundMode::Floor`] by default
+    ///
+    /// The carry propagates to the larger units and past the largest enabled
+    /// one, which just grows, so `23h40m` rounded to hours is `"1d"` with days
+    /// enabled and `"24h"` without. If rounding up would overflow the value is
+    /// floored instead.
+    ///
+    /// Example:
+    ///```
+    /// use compound_duration::{DurationFormatter, RoundMode};
+    ///
+    /// let hours = DurationFormatter::new()
+    ///     .with_minutes(false)
+    ///     .with_seconds(false)
+    ///     .rounding(RoundMode::HalfUp);
+    /// assert_eq!(hours.format(6000), "2h");
+    /// assert_eq!(hours.format(85_200), "1d");
+    ///```
+    #[must_use]
+    pub const fn rounding(mut self, mode: RoundMode) -> Self {
+        self.rounding = mode;
+        self
+    }
+
+    /// Set the order the units are written in, largest first by default
+    ///
+    /// Only the order of the output changes, the values are the same.
+    ///
+    /// Example:
+    ///```
+    /// use compound_duration::{DurationFormatter, Order};
+    ///
+    /// let formatter = DurationFormatter::new().order(Order::SmallestFirst);
+    /// assert_eq!(formatter.format(6_000_000), "40m10h69d");
+    ///```
+    #[must_use]
+    pub const fn order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Format seconds using the enabled units
+    #[must_use]
+    pub fn format(&self, seconds: u64) -> String {
+        let mut compound_duration = String::new();
+        // writing to a String never fails
+        self.write(&mut compound_duration, seconds).unwrap();
+        compound_duration
+    }
+
+    /// Format nanoseconds using the enabled units
+    #[must_use]
+    pub fn format_nanos(&self, nanos: u64) -> String {
+        let mut compound_duration = String::new();
+        // writing to a String never fails
+        self.write_nanos(&mut compound_duration, nanos).unwrap();
+        compound_duration
+    }
+
+    /// Write seconds using the enabled units to `out`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if writing to `out` fails.
+    pub fn write<W: fmt::Write>(&self, out: &mut W, seconds: u64) -> fmt::Result {
+        self.write_total(out, u128::from(seconds), NANOS_PER_SECOND as u128)
+    }
+
+    /// Write nanoseconds using the enabled units to `out`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if writing to `out` fails.
+    pub fn write_nanos<W: fmt::Write>(&self, out: &mut W, nanos: u64) -> fmt::Result {
+        self.write_total(out, u128::from(nanos), 1)
+    }
+
+    /// Write `total`, counted in units of `base` nanoseconds, units smaller
+    /// than `base` are skipped
+    pub(crate) fn write_total<W: fmt::Write>(
+        &self,
+        out: &mut W,
+        total: u128,
+        base: u128,
+    ) -> fmt::Result {
+        match self.order {
+            Order::LargestFirst => self.write_parts(out, self.parts(total, base)),
+            Order::SmallestFirst => {
+                let parts: Vec<_> = self.parts(total, base).collect();
+                self.write_parts(out, parts.into_iter().rev())
+            }
+        }
+    }
+
+    /// Write `parts` separated by the separator, nothing is zero
+    fn write_parts<W: fmt::Write>(
+        &self,
+        out: &mut W,
+        parts: impl Iterator<Item = (u128, &'a str)>,
+    ) -> fmt::Result {
+        let mut empty = true;
+        for (value, label) in parts {
+            if !empty {
+                out.write_str(self.separator)?;
+            }
+            write!(out, "{value}{label}")?;
+            empty = false;
+        }
+
+        if empty {
+            let smallest = self.units().last().map_or("s", |(label, _)| label);
+            write!(out, "0{smallest}")?;
+        }
+
+        Ok(())
+    }
+
+    /// Non-zero `(value, label)` pairs of `total`, counted in units of `base`
+    /// nanoseconds, largest first
+    fn parts(&self, total: u128, base: u128) -> impl Iterator<Item = (u128, &'a str)> + '_ {
+        // size of the smallest unit that will be shown, counted in `base`
+        let step = self
+            .units()
+            .map(|(_, size)| size)
+            .filter(|size| *size >= base)
+            .last();
+        let mut rest = match step {
+            Some(step) => round_wide(total, step / base, self.rounding),
+            None => total,
+        };
+        self.units()
+            .filter(move |(_, size)| *size >= base)
+            .filter_map(move |(label, size)| {
+                let size = size / base;
+                let value = rest / size;
+                rest %= size;
+                (value != 0).then_some((value, label))
+            })
+    }
+
+    /// Enabled units, largest first, built-in units go before custom units of
+    /// the same size
+    fn units(&self) -> impl Iterator<Item = (&'a str, u128)> + '_ {
+        let mut builtin = BUILTIN
+            .iter()
+            .zip(self.builtin.iter())
+            .enumerate()
+            .filter(|(_, (_, enabled))| **enabled)
+            .map(move |(index, (unit, _))| match index {
+                MICROS if self.ascii => ("us", unit.1),
+                _ => *unit,
+            })
+            .peekable();
+        let mut custom = self.custom.iter().copied().peekable();
+        core::iter::from_fn(move || match (builtin.peek(), custom.peek()) {
+            (Some(b), Some(c)) if c.1 > b.1 => custom.next(),
+            (Some(_), _) => builtin.next(),
+            (None, _) => custom.next(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DurationFormatter, Order};
+    use crate::{format_dhms, format_ns, format_wdhms, RoundMode};
+
+    #[test]
+    fn test_duration_formatter() {
+        let dhms = DurationFormatter::new();
+        assert_eq!(dhms.format(0), "0s");
+        assert_eq!(dhms.format(6_000_000), "69d10h40m");
+        assert_eq!(dhms.format(u64::MAX), format_dhms(u64::MAX));
+        assert_eq!(DurationFormatter::default(), dhms);
+
+        let wdhms = DurationFormatter::new().with_weeks(true);
+        assert_eq!(wdhms.format(6_000_000), format_wdhms(6_000_000));
+
+        let ns = DurationFormatter::new()
+            .with_millis(true)
+            .with_micros(true)
+            .with_nanos(true);
+        assert_eq!(ns.format_nanos(0), "0ns");
+        assert_eq!(ns.format_nanos(3_000_129_723), format_ns(3_000_129_723_u64));
+        assert_eq!(ns.format(1), "1s");
+        assert_eq!(ns.format_nanos(1000), "1\u{b5}s");
+
+        let ascii = ns.clone().ascii(true);
+        assert_eq!(ascii.format_nanos(1000), "1us");
+        assert_eq!(ascii.format_nanos(3_000_129_723), "3s129us723ns");
+        assert_eq!(ascii.clone().with_nanos(false).format_nanos(0), "0us");
+        assert_eq!(ascii.ascii(false), ns);
+
+        let spaced = DurationFormatter::new().separator(" ");
+        assert_eq!(spaced.format(0), "0s");
+        assert_eq!(spaced.format(30), "30s");
+        assert_eq!(spaced.format(6_000_000), "69d 10h 40m");
+
+        // remainder below the smallest unit is discarded
+        let hours = DurationFormatter::new()
+            .with_minutes(false)
+            .with_seconds(false);
+        assert_eq!(hours.format(3599), "0h");
+        assert_eq!(hours.format(6_000_000), "69d10h");
+        assert_eq!(DurationFormatter::new().format_nanos(999_999_999), "0s");
+
+        // custom units
+        let decades = DurationFormatter::new()
+            .with_unit("dec", 10 * 365 * 86400)
+            .with_unit("y", 365 * 86400);
+        assert_eq!(decades.format(4_294_967_295), "13dec6y70d6h28m15s");
+
+        let custom_only = DurationFormatter::new()
+            .with_days(false)
+            .with_hours(false)
+            .with_minutes(false)
+            .with_seconds(false)
+            .with_unit("q", 15 * 60)
+            .separator(", ");
+        assert_eq!(custom_only.format(3600), "4q");
+        assert_eq!(custom_only.format(60), "0q");
+
+        let none = DurationFormatter::new()
+            .with_days(false)
+            .with_hours(false)
+            .with_minutes(false)
+            .with_seconds(false);
+        assert_eq!(none.format(3600), "0s");
+    }
+
+    #[test]
+    fn test_duration_formatter_custom_order() {
+        let day = 86_400;
+        let fortnights = DurationFormatter::new()
+            .with_weeks(true)
+            .with_unit("fn", 14 * day);
+        assert_eq!(fortnights.format(0), "0s");
+        assert_eq!(fortnights.format(13 * day), "1w6d");
+        assert_eq!(fortnights.format(14 * day), "1fn");
+        assert_eq!(fortnights.format(22 * day + 1), "1fn1w1d1s");
+        assert_eq!(fortnights.format(6_000_000), "4fn1w6d10h40m");
+
+        // registration order doesn't change the output
+        let decades = DurationFormatter::new()
+            .with_weeks(true)
+            .with_unit("fn", 14 * day)
+            .with_unit("dec", 10 * 365 * day);
+        let reversed = DurationFormatter::new()
+            .with_unit("dec", 10 * 365 * day)
+            .with_unit("fn", 14 * day)
+            .with_weeks(true);
+        assert_eq!(decades, reversed);
+        for seconds in (0..10).map(|i| i * (u64::MAX / 10)).chain([400_000_000]) {
+            assert_eq!(decades.format(seconds), reversed.format(seconds));
+        }
+        assert_eq!(decades.format(400_000_000), "1dec69fn1w6d15h6m40s");
+        assert_eq!(decades.format(10 * 365 * day + 14 * day), "1dec1fn");
+
+        // built-in units win ties, then custom units in registration order
+        let ties = DurationFormatter::new()
+            .with_unit("day", day)
+            .with_unit("hour", 3600)
+            .with_unit("sixty", 3600)
+            .separator(" ");
+        assert_eq!(ties.format(90_061), "1d 1h 1m 1s");
+        let ties = ties.with_days(false).with_hours(false);
+        assert_eq!(ties.format(90_061), "1day 1hour 1m 1s");
+        let ties = DurationFormatter::new()
+            .with_days(false)
+            .with_hours(false)
+            .with_unit("sixty", 3600)
+            .with_unit("hour", 3600);
+        assert_eq!(ties.format(7200), "2sixty");
+    }
+
+    #[test]
+    #[should_panic(expected = "a unit can't be zero seconds long")]
+    fn test_duration_formatter_zero_unit() {
+        let _ = DurationFormatter::new().with_unit("z", 0);
+    }
+
+    #[test]
+    fn test_duration_formatter_rounding() {
+        let hours = DurationFormatter::new()
+            .with_minutes(false)
+            .with_seconds(false);
+        // truncated by default
+        assert_eq!(hours.format(6000), "1h");
+        assert_eq!(hours.clone().rounding(RoundMode::Floor), hours);
+
+        let half_up = hours.clone().rounding(RoundMode::HalfUp);
+        assert_eq!(half_up.format(0), "0h");
+        assert_eq!(half_up.format(1799), "0h");
+        assert_eq!(half_up.format(1800), "1h");
+        assert_eq!(half_up.format(6000), "2h");
+        assert_eq!(half_up.format(6_000_000), "69d11h");
+        // the carry propagates to days
+        assert_eq!(half_up.format(85_200), "1d");
+        assert_eq!(half_up.format(u64::MAX), "213503982334601d7h");
+
+        // with days capped the carry stays in the hours
+        let capped = half_up.clone().with_days(false);
+        assert_eq!(capped.format(85_200), "24h");
+        assert_eq!(capped.format(6_000_000), "1667h");
+        assert_eq!(capped.format(5400), "2h");
+
+        let half_even = capped.clone().rounding(RoundMode::HalfEven);
+        assert_eq!(half_even.format(5400), "2h");
+        assert_eq!(half_even.format(9000), "2h");
+        let ceil = capped.rounding(RoundMode::Ceil);
+        assert_eq!(ceil.format(3601), "2h");
+        assert_eq!(ceil.format(3600), "1h");
+
+        // units under the base are not the smallest one shown
+        let ns = DurationFormatter::new()
+            .with_millis(true)
+            .rounding(RoundMode::HalfUp);
+        assert_eq!(ns.format(61), "1m1s");
+        assert_eq!(ns.format_nanos(1_999_500), "2ms");
+        assert_eq!(ns.format_nanos(59_999_500_000), "1m");
+
+        // custom units round too
+        let quarters = DurationFormatter::new()
+            .with_days(false)
+            .with_hours(false)
+            .with_minutes(false)
+            .with_seconds(false)
+            .with_unit("q", 15 * 60)
+            .rounding(RoundMode::HalfUp);
+        assert_eq!(quarters.format(450), "1q");
+        assert_eq!(quarters.format(449), "0q");
+    }
+
+    #[test]
+    fn test_duration_formatter_order() {
+        let largest = DurationFormatter::new().order(Order::LargestFirst);
+        let smallest = DurationFormatter::new().order(Order::SmallestFirst);
+        assert_eq!(largest, DurationFormatter::new());
+        assert_eq!(Order::default(), Order::LargestFirst);
+
+        assert_eq!(largest.format(0), "0s");
+        assert_eq!(smallest.format(0), "0s");
+        assert_eq!(largest.format(6_000_000), "69d10h40m");
+        assert_eq!(smallest.format(6_000_000), "40m10h69d");
+        assert_eq!(smallest.format(86_401), "1s1d");
+        assert_eq!(smallest.format(u64::MAX), "15s7h213503982334601d");
+
+        let spaced = smallest.clone().separator(" ").with_weeks(true);
+        assert_eq!(spaced.format(6_000_000), "40m 10h 6d 9w");
+        let ns = smallest
+            .with_millis(true)
+            .with_micros(true)
+            .with_nanos(true);
+        assert_eq!(ns.format_nanos(0), "0ns");
+        assert_eq!(ns.format_nanos(3_000_129_723), "723ns129\u{b5}s3s");
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{components_wdhms, to_u64, DurationComponents};
+use alloc::string::String;
+use core::convert::{TryFrom, TryInto};
+use core::fmt::{Debug, Write};
+use core::ops::BitAnd;
+
+/// Append `value` to `out` with `sep` between every group of three digits
+fn push_grouped(out: &mut String, value: u64, sep: char) {
+    let mut digits = String::new();
+    // writing to a String never fails
+    write!(digits, "{value}").unwrap();
+
+    for (i, digit) in digits.chars().enumerate() {
+        if i != 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(sep);
+        }
+        out.push(digit);
+    }
+}
+
+/// Convert seconds to compound duration (days, hours, minutes, seconds) with
+/// the digits of every value grouped in thousands by `sep`
+///
+/// Only the numbers are grouped, the unit suffixes are the same as in
+/// [`format_dhms`](crate::format_dhms). In practice only the days get long
+/// enough to be grouped.
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_grouped;
+///
+/// assert_eq!(format_dhms_grouped(4_294_967_295_u32, ','), "49,710d6h28m15s");
+/// assert_eq!(format_dhms_grouped(6_000_000, ','), "69d10h40m");
+///```
+#[must_use]
+pub fn format_dhms_grouped<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    sep: char,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let DurationComponents {
+        weeks,
+        days,
+        hours,
+        minutes,
+        seconds,
+    } = components_wdhms(to_u64(seconds));
+    let parts = [
+        (weeks * 7 + days, 'd'),
+        (hours, 'h'),
+        (minutes, 'm'),
+        (seconds, 's'),
+    ];
+
+    let mut compound_duration = String::new();
+    for &(value, suffix) in parts.iter().filter(|(value, _)| *value != 0) {
+        push_grouped(&mut compound_duration, value, sep);
+        compound_duration.push(suffix);
+    }
+
+    if compound_duration.is_empty() {
+        compound_duration.push_str("0s");
+    }
+    compound_duration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_dhms_grouped;
+
+    #[test]
+    fn test_format_dhms_grouped() {
+        assert_eq!(format_dhms_grouped(0, ','), "0s");
+        assert_eq!(format_dhms_grouped(61, ','), "1m1s");
+        assert_eq!(format_dhms_grouped(6_000_000, ','), "69d10h40m");
+        assert_eq!(format_dhms_grouped(86_400 * 999, ','), "999d");
+        assert_eq!(format_dhms_grouped(86_400 * 1000, ','), "1,000d");
+        assert_eq!(format_dhms_grouped(86_400_u64 * 100_000, ','), "100,000d");
+        assert_eq!(
+            format_dhms_grouped(4_294_967_295_u32, ','),
+            "49,710d6h28m15s"
+        );
+        assert_eq!(
+            format_dhms_grouped(4_294_967_295_u32, '\u{a0}'),
+            "49\u{a0}710d6h28m15s"
+        );
+        assert_eq!(
+            format_dhms_grouped(u64::MAX, '_'),
+            "213_503_982_334_601d7h15s"
+        );
+    }
+}
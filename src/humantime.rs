@@ -0,0 +1,102 @@
+use crate::{dhms_parts, to_u64};
+use alloc::string::String;
+use core::convert::{TryFrom, TryInto};
+use core::fmt::{Debug, Write};
+use core::ops::BitAnd;
+
+/// A humantime year, 365.25 days
+const YEAR: u64 = 31_557_600;
+/// A humantime month, 30.44 days
+const MONTH: u64 = 2_630_016;
+
+/// Convert seconds to compound duration spelled like the `humantime` crate
+///
+/// Matches the output of `humantime::format_duration` in `humantime` 2.1 for
+/// whole seconds, so downstream string matching keeps working when switching
+/// between the two crates:
+///
+/// * years are 365.25 days and months 30.44 days, `"1year"`, `"2months"`
+/// * days are spelled out, `"1day"`, `"2days"`
+/// * hours, minutes and seconds use `h`, `m` and `s`
+/// * the units are separated by a single space and zero is `"0s"`
+///
+/// Example:
+///```
+/// use compound_duration::format_humantime_compatible;
+///
+/// assert_eq!(format_humantime_compatible(6_000_000), "2months 8days 13h 32m 48s");
+/// assert_eq!(format_humantime_compatible(93_784), "1day 2h 3m 4s");
+/// assert_eq!(format_humantime_compatible(0), "0s");
+///```
+#[must_use]
+pub fn format_humantime_compatible<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let seconds = to_u64(seconds);
+    if seconds == 0 {
+        return String::from("0s");
+    }
+
+    let [(days, _), (hours, _), (minutes, _), (secs, _)] = dhms_parts(seconds % YEAR % MONTH);
+    let parts = [
+        (seconds / YEAR, "year", true),
+        (seconds % YEAR / MONTH, "month", true),
+        (days, "day", true),
+        (hours, "h", false),
+        (minutes, "m", false),
+        (secs, "s", false),
+    ];
+
+    let mut compound_duration = String::new();
+    for &(value, label, plural) in parts.iter().filter(|(value, _, _)| *value != 0) {
+        if !compound_duration.is_empty() {
+            compound_duration.push(' ');
+        }
+        // writing to a String never fails
+        write!(compound_duration, "{value}{label}").unwrap();
+        if plural && value > 1 {
+            compound_duration.push('s');
+        }
+    }
+
+    compound_duration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_humantime_compatible;
+
+    #[test]
+    fn test_format_humantime_compatible() {
+        // outputs of humantime::format_duration(Duration::from_secs(n))
+        assert_eq!(format_humantime_compatible(0), "0s");
+        assert_eq!(format_humantime_compatible(1), "1s");
+        assert_eq!(format_humantime_compatible(61), "1m 1s");
+        assert_eq!(format_humantime_compatible(3600), "1h");
+        assert_eq!(format_humantime_compatible(7259), "2h 59s");
+        assert_eq!(format_humantime_compatible(9420), "2h 37m");
+        assert_eq!(format_humantime_compatible(86_400), "1day");
+        assert_eq!(format_humantime_compatible(172_800), "2days");
+        assert_eq!(format_humantime_compatible(93_784), "1day 2h 3m 4s");
+        assert_eq!(format_humantime_compatible(2_630_016), "1month");
+        assert_eq!(format_humantime_compatible(5_260_032), "2months");
+        assert_eq!(
+            format_humantime_compatible(6_000_000),
+            "2months 8days 13h 32m 48s"
+        );
+        assert_eq!(format_humantime_compatible(31_557_600), "1year");
+        assert_eq!(format_humantime_compatible(63_115_200), "2years");
+        assert_eq!(
+            format_humantime_compatible(34_187_616 + 86_400 + 3600 + 60 + 1),
+            "1year 1month 1day 1h 1m 1s"
+        );
+        assert_eq!(
+            format_humantime_compatible(u64::MAX),
+            "584542046090years 7months 15days 17h 5m 3s"
+        );
+    }
+}
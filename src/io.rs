@@ -0,0 +1,87 @@
+use crate::buf::{StackBuf, MAX_DHMS_LEN};
+use crate::write_dhms;
+use std::io;
+
+/// Write seconds as compound duration (days, hours, minutes, seconds) to an
+/// [`io::Write`]
+///
+/// Same output as [`format_dhms`](crate::format_dhms) written to a byte sink
+/// like stdout or a file. The duration is formatted on the stack and written
+/// with a single `write_all`, nothing is allocated.
+///
+/// # Errors
+///
+/// Returns the [`io::Error`] of `out` if writing fails.
+///
+/// Example:
+///```
+/// use compound_duration::write_dhms_io;
+///
+/// let mut out = Vec::new();
+/// write_dhms_io(&mut out, 6_000_000).unwrap();
+/// assert_eq!(out, b"69d10h40m");
+///
+/// write_dhms_io(&mut std::io::stdout(), 61).unwrap();
+///```
+pub fn write_dhms_io<W: io::Write>(out: &mut W, seconds: u64) -> io::Result<()> {
+    let mut buf = StackBuf::<MAX_DHMS_LEN>::new();
+    // the buffer fits the longest output
+    write_dhms(&mut buf, seconds).unwrap();
+    out.write_all(buf.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_dhms_io, MAX_DHMS_LEN};
+    use crate::{format_dhms, formatted_len_dhms};
+    use std::io;
+    use std::string::ToString;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_write_dhms_io() {
+        let mut out = Vec::new();
+        write_dhms_io(&mut out, 0).unwrap();
+        assert_eq!(out, b"0s");
+
+        let mut out = Vec::new();
+        write_dhms_io(&mut out, 6_000_000).unwrap();
+        write_dhms_io(&mut out, 61).unwrap();
+        assert_eq!(out, b"69d10h40m1m1s");
+
+        for seconds in (0..1000)
+            .map(|i| i * (u64::MAX / 1000))
+            .chain([u64::MAX, u64::MAX - 1])
+        {
+            let mut out = Vec::new();
+            write_dhms_io(&mut out, seconds).unwrap();
+            assert_eq!(out, format_dhms(seconds).as_bytes());
+            assert!(formatted_len_dhms(seconds) <= MAX_DHMS_LEN);
+        }
+        let longest = 213_503_982_334_600 * 86_400 + 86_399;
+        assert_eq!(formatted_len_dhms(longest), MAX_DHMS_LEN);
+        let mut out = Vec::new();
+        write_dhms_io(&mut out, longest).unwrap();
+        assert_eq!(out, b"213503982334600d23h59m59s");
+    }
+
+    #[test]
+    fn test_write_dhms_io_error() {
+        let mut full = [0_u8; 4];
+        let err = write_dhms_io(&mut &mut full[..], 6_000_000).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+
+        struct Broken;
+        impl io::Write for Broken {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::other("broken pipe"))
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+        let err = write_dhms_io(&mut Broken, 61).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+        assert_eq!(err.to_string(), "broken pipe");
+    }
+}
@@ -0,0 +1,201 @@
+use crate::parse::{parse_ordered, UnitTable};
+use crate::{
+    to_u64, ParseError, MONTH, SECONDS_PER_DAY, SECONDS_PER_HOUR, SECONDS_PER_MINUTE,
+    SECONDS_PER_WEEK, YEAR,
+};
+use alloc::string::String;
+use core::convert::{TryFrom, TryInto};
+use core::fmt::{Debug, Write};
+use core::ops::BitAnd;
+
+/// Convert seconds to an ISO 8601 duration (`PnDTnHnMnS`)
+///
+/// Years and months don't have a fixed length so the largest unit used is
+/// days, unless the duration is a whole number of weeks, then the `PnW` form
+/// is used. Zero components are omitted and zero is `"PT0S"`.
+///
+/// Example:
+///```
+/// use compound_duration::format_iso8601;
+///
+/// assert_eq!(format_iso8601(93_784), "P1DT2H3M4S");
+/// assert_eq!(format_iso8601(1_209_600), "P2W");
+/// assert_eq!(format_iso8601(0), "PT0S");
+///```
+#[must_use]
+pub fn format_iso8601<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(seconds: T) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let seconds = to_u64(seconds);
+    if seconds == 0 {
+        return String::from("PT0S");
+    }
+
+    let (week, day, hour, minute) = (
+        SECONDS_PER_WEEK as u64,
+        SECONDS_PER_DAY as u64,
+        SECONDS_PER_HOUR as u64,
+        SECONDS_PER_MINUTE as u64,
+    );
+    let mut duration = String::from("P");
+
+    // writing to a String never fails
+    if seconds.is_multiple_of(week) {
+        write!(duration, "{}W", seconds / week).unwrap();
+        return duration;
+    }
+
+    // days
+    let ds = seconds / day;
+    if ds != 0 {
+        write!(duration, "{ds}D").unwrap();
+    }
+
+    // time components, after the T separator
+    let sec = seconds % day;
+    if sec != 0 {
+        duration.push('T');
+        for (value, designator) in [
+            (sec / hour, 'H'),
+            (sec % hour / minute, 'M'),
+            (sec % minute, 'S'),
+        ] {
+            if value != 0 {
+                write!(duration, "{value}{designator}").unwrap();
+            }
+        }
+    }
+
+    duration
+}
+
+const DATE_UNITS: &UnitTable = &[
+    (&["Y"], YEAR as u64),
+    (&["M"], MONTH as u64),
+    (&["W"], SECONDS_PER_WEEK as u64),
+    (&["D"], SECONDS_PER_DAY as u64),
+];
+
+const TIME_UNITS: &UnitTable = &[
+    (&["H"], SECONDS_PER_HOUR as u64),
+    (&["M"], SECONDS_PER_MINUTE as u64),
+    (&["S"], 1),
+];
+
+/// Parse an ISO 8601 duration (`PnYnMnWnDTnHnMnS`) into seconds
+///
+/// `M` before the `T` separator are months and after it minutes. Years and
+/// months don't have a fixed length, a year is taken as [`YEAR`] (365 days)
+/// and a month as [`MONTH`] (30 days). Only whole numbers are supported.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the input is not an ISO 8601 duration, has a
+/// fractional value, units out of order or the total overflows a `u64`.
+///
+/// Example:
+///```
+/// use compound_duration::{parse_iso8601, ParseError};
+///
+/// assert_eq!(parse_iso8601("P1DT2H3M4S"), Ok(93_784));
+/// assert_eq!(parse_iso8601("P2W"), Ok(1_209_600));
+/// assert_eq!(parse_iso8601("PT1.5S"), Err(ParseError::Fractional(3)));
+///```
+pub fn parse_iso8601(input: &str) -> Result<u64, ParseError> {
+    if input.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    if let Some(pos) = input.find(['.', ',']) {
+        return Err(ParseError::Fractional(pos));
+    }
+
+    let rest = input
+        .strip_prefix('P')
+        .ok_or(ParseError::InvalidFormat(0))?;
+    let (date, time) = match rest.find('T') {
+        Some(pos) => (&rest[..pos], Some((pos + 2, &rest[pos + 1..]))),
+        None => (rest, None),
+    };
+
+    // there must be at least one component and a T must be followed by one
+    if (date.is_empty() && time.is_none()) || time.is_some_and(|(_, time)| time.is_empty()) {
+        return Err(ParseError::InvalidFormat(input.len()));
+    }
+
+    let mut total = 0;
+    if !date.is_empty() {
+        total = parse_ordered(date, DATE_UNITS).map_err(|e| e.shifted(1))?;
+    }
+    if let Some((offset, time)) = time {
+        let seconds = parse_ordered(time, TIME_UNITS).map_err(|e| e.shifted(offset))?;
+        total = total.checked_add(seconds).ok_or(ParseError::Overflow)?;
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_iso8601, parse_iso8601};
+    use crate::ParseError;
+
+    #[test]
+    fn test_format_iso8601() {
+        assert_eq!(format_iso8601(0), "PT0S");
+        assert_eq!(format_iso8601(1), "PT1S");
+        assert_eq!(format_iso8601(61), "PT1M1S");
+        assert_eq!(format_iso8601(3600), "PT1H");
+        assert_eq!(format_iso8601(7259), "PT2H59S");
+        assert_eq!(format_iso8601(86400), "P1D");
+        assert_eq!(format_iso8601(86401), "P1DT1S");
+        assert_eq!(format_iso8601(93_784), "P1DT2H3M4S");
+        assert_eq!(format_iso8601(604_800), "P1W");
+        assert_eq!(format_iso8601(604_801), "P7DT1S");
+        assert_eq!(format_iso8601(1_209_600), "P2W");
+        assert_eq!(format_iso8601(1_296_000), "P15D");
+        assert_eq!(format_iso8601(6_000_000), "P69DT10H40M");
+    }
+
+    #[test]
+    fn test_parse_iso8601() {
+        assert_eq!(parse_iso8601("PT0S"), Ok(0));
+        assert_eq!(parse_iso8601("P0D"), Ok(0));
+        assert_eq!(parse_iso8601("PT1M1S"), Ok(61));
+        assert_eq!(parse_iso8601("P1DT2H3M4S"), Ok(93_784));
+        assert_eq!(parse_iso8601("P2W"), Ok(1_209_600));
+        assert_eq!(parse_iso8601("P1W1D"), Ok(691_200));
+        assert_eq!(parse_iso8601("PT36H"), Ok(129_600));
+
+        // M is months before T and minutes after it
+        assert_eq!(parse_iso8601("P1M"), Ok(2_592_000));
+        assert_eq!(parse_iso8601("PT1M"), Ok(60));
+        assert_eq!(parse_iso8601("P1MT1M"), Ok(2_592_060));
+        assert_eq!(parse_iso8601("P1Y2M3DT4H5M6S"), Ok(36_993_906));
+
+        for seconds in (0..10_000_000).step_by(9973) {
+            assert_eq!(parse_iso8601(&format_iso8601(seconds)), Ok(seconds));
+        }
+
+        assert_eq!(parse_iso8601(""), Err(ParseError::Empty));
+        assert_eq!(parse_iso8601("P"), Err(ParseError::InvalidFormat(1)));
+        assert_eq!(parse_iso8601("PT"), Err(ParseError::InvalidFormat(2)));
+        assert_eq!(parse_iso8601("P1DT"), Err(ParseError::InvalidFormat(4)));
+        assert_eq!(parse_iso8601("1D"), Err(ParseError::InvalidFormat(0)));
+        assert_eq!(parse_iso8601("PT1.5S"), Err(ParseError::Fractional(3)));
+        assert_eq!(parse_iso8601("PT1,5S"), Err(ParseError::Fractional(3)));
+        assert_eq!(parse_iso8601("P1H"), Err(ParseError::UnknownUnit(2)));
+        assert_eq!(parse_iso8601("PT1D"), Err(ParseError::UnknownUnit(3)));
+        assert_eq!(parse_iso8601("P1d"), Err(ParseError::UnknownUnit(2)));
+        assert_eq!(parse_iso8601("P1D1Y"), Err(ParseError::OutOfOrder(4)));
+        assert_eq!(parse_iso8601("PT1S1H"), Err(ParseError::OutOfOrder(5)));
+        assert_eq!(parse_iso8601("PTS"), Err(ParseError::MissingNumber(2)));
+        assert_eq!(parse_iso8601("PT5"), Err(ParseError::MissingUnit(3)));
+        assert_eq!(parse_iso8601("P213503982334601DT7H15S"), Ok(u64::MAX));
+        assert_eq!(
+            parse_iso8601("P213503982334601DT7H16S"),
+            Err(ParseError::Overflow)
+        );
+    }
+}
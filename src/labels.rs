@@ -0,0 +1,156 @@
+use crate::{dhms_parts, to_u64};
+use alloc::string::String;
+use core::convert::{TryFrom, TryInto};
+use core::fmt::{Debug, Write};
+use core::ops::BitAnd;
+
+/// Suffixes used for each unit, and the separator placed between units
+///
+/// The labels are borrowed, so a table for another language can live in a
+/// `const` or a `static` and be reused without allocating.
+///
+/// Example:
+///```
+/// use compound_duration::UnitLabels;
+///
+/// const GERMAN: UnitLabels = UnitLabels {
+///     week: "Wo",
+///     day: "T",
+///     hour: "Std",
+///     minute: "Min",
+///     second: "Sek",
+///     separator: " ",
+///     ..UnitLabels::DEFAULT
+/// };
+/// assert_eq!(GERMAN.day, "T");
+///```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnitLabels<'a> {
+    pub week: &'a str,
+    pub day: &'a str,
+    pub hour: &'a str,
+    pub minute: &'a str,
+    pub second: &'a str,
+    pub milli: &'a str,
+    pub micro: &'a str,
+    pub nano: &'a str,
+    pub separator: &'a str,
+}
+
+impl UnitLabels<'static> {
+    /// The labels used by [`format_dhms`](crate::format_dhms) and friends
+    pub const DEFAULT: Self = Self {
+        week: "w",
+        day: "d",
+        hour: "h",
+        minute: "m",
+        second: "s",
+        milli: "ms",
+        micro: "\u{b5}s",
+        nano: "ns",
+        separator: "",
+    };
+}
+
+impl Default for UnitLabels<'_> {
+    fn default() -> Self {
+        UnitLabels::DEFAULT
+    }
+}
+
+/// Convert seconds to compound duration (days, hours, minutes, seconds) using
+/// the suffixes and separator from `labels`
+///
+/// With [`UnitLabels::DEFAULT`] the output is the same as
+/// [`format_dhms`](crate::format_dhms).
+///
+/// Example:
+///```
+/// use compound_duration::{format_dhms_with_labels, UnitLabels};
+///
+/// let german = UnitLabels {
+///     day: "T",
+///     hour: "Std",
+///     minute: "Min",
+///     separator: " ",
+///     ..UnitLabels::DEFAULT
+/// };
+/// assert_eq!(format_dhms_with_labels(6_000_000, &german), "69T 10Std 40Min");
+/// assert_eq!(
+///     format_dhms_with_labels(6_000_000, &UnitLabels::DEFAULT),
+///     "69d10h40m"
+/// );
+///```
+#[must_use]
+pub fn format_dhms_with_labels<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    labels: &UnitLabels<'_>,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let seconds = to_u64(seconds);
+    if seconds == 0 {
+        let mut compound_duration = String::from("0");
+        compound_duration.push_str(labels.second);
+        return compound_duration;
+    }
+
+    let [(days, _), (hours, _), (minutes, _), (secs, _)] = dhms_parts(seconds);
+    let parts = [
+        (days, labels.day),
+        (hours, labels.hour),
+        (minutes, labels.minute),
+        (secs, labels.second),
+    ];
+
+    let mut compound_duration = String::new();
+    for &(value, label) in parts.iter().filter(|(value, _)| *value != 0) {
+        if !compound_duration.is_empty() {
+            compound_duration.push_str(labels.separator);
+        }
+        // writing to a String never fails
+        write!(compound_duration, "{value}{label}").unwrap();
+    }
+    compound_duration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_dhms_with_labels, UnitLabels};
+    use crate::format_dhms;
+
+    #[test]
+    fn test_format_dhms_with_labels() {
+        let default = UnitLabels::DEFAULT;
+        for &seconds in &[0_u64, 1, 61, 3600, 86_401, 6_000_000, u64::MAX] {
+            assert_eq!(
+                format_dhms_with_labels(seconds, &default),
+                format_dhms(seconds)
+            );
+        }
+        assert_eq!(UnitLabels::default(), UnitLabels::DEFAULT);
+
+        let german = UnitLabels {
+            week: "Wo",
+            day: "T",
+            hour: "Std",
+            minute: "Min",
+            second: "Sek",
+            separator: " ",
+            ..UnitLabels::DEFAULT
+        };
+        assert_eq!(format_dhms_with_labels(0, &german), "0Sek");
+        assert_eq!(format_dhms_with_labels(61, &german), "1Min 1Sek");
+        assert_eq!(format_dhms_with_labels(86_400, &german), "1T");
+        assert_eq!(
+            format_dhms_with_labels(6_000_000, &german),
+            "69T 10Std 40Min"
+        );
+        assert_eq!(
+            format_dhms_with_labels(4_294_967_295_u32, &german),
+            "49710T 6Std 28Min 15Sek"
+        );
+    }
+}
@@ -1,21 +1,301 @@
 //! Convert seconds to compound duration (week, days, hours, minutes, seconds)
+//!
+//! The crate is `no_std` and only needs `alloc`, the default `std` feature
+//! adds the parts that depend on the standard library.
 
-use std::convert::{TryFrom, TryInto};
-use std::fmt::Debug;
-use std::ops::BitAnd;
+#![no_std]
 
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use core::convert::{TryFrom, TryInto};
+use core::fmt::{self, Debug};
+use core::ops::BitAnd;
+use core::time::Duration;
+
+mod aggregate;
+mod buf;
+mod business;
+mod calendar;
+#[cfg(feature = "chrono")]
+mod chrono_support;
+mod clock;
+mod components;
+mod decimal;
+mod display;
+mod duration;
+#[cfg(feature = "std")]
+mod elapsed;
+mod ext;
+mod float;
+mod formatter;
+mod grouped;
+mod humantime;
+#[cfg(feature = "std")]
+mod io;
+mod iso8601;
+mod labels;
+mod long;
+mod macros;
+mod padded;
+mod parse;
+#[cfg(feature = "serde")]
+pub mod serde_secs;
+mod signed;
+#[cfg(feature = "smallstr")]
+mod small;
+mod subsecond;
+#[cfg(feature = "time")]
+mod time_support;
+mod truncate;
+mod unit;
+mod width;
+
+pub use aggregate::{format_max, format_min, format_sum, format_with_share};
+pub use business::format_business;
+pub use calendar::{format_ywdhms, format_ywdhms_with, YearLength};
+#[cfg(feature = "chrono")]
+pub use chrono_support::format_chrono;
+pub use clock::{format_clock, format_ms};
+pub use components::{
+    components_wdhms, format_json_components, iter_wdhms, parts_ns, split_wdhms, styled_parts_dhms,
+    DurationComponents,
+};
+pub use decimal::{format_ns_decimal, format_ns_decimal_trimmed};
+pub use display::Dhms;
+pub use duration::CompoundDuration;
+#[cfg(feature = "std")]
+pub use elapsed::{format_elapsed, format_elapsed_dhms};
+pub use ext::DurationFormat;
+pub use float::{format_secs_f64, try_format_secs_f64};
+pub use formatter::{DurationFormatter, Order};
+pub use grouped::format_dhms_grouped;
+pub use humantime::format_humantime_compatible;
+#[cfg(feature = "std")]
+pub use io::write_dhms_io;
+pub use iso8601::{format_iso8601, parse_iso8601};
+pub use labels::{format_dhms_with_labels, UnitLabels};
+pub use long::{
+    format_dhms_long, format_dhms_prose, format_dhms_styled, write_dhms_long, LabelStyle,
+};
+#[doc(hidden)]
+pub use macros::__compound_duration;
+pub use padded::{
+    format_dhms_min_units, format_dhms_padded, format_wdhms_colon, format_wdhms_full,
+};
+pub use parse::{
+    parse_dhms, parse_dhms_lenient, parse_dhms_prefix, parse_dhms_strict, parse_ns,
+    parse_to_std_duration, parse_wdhms, parse_wdhms_lenient,
+};
+pub use signed::{
+    format_between, format_dhms_signed, format_dhms_signed_with, format_relative, format_remaining,
+    format_since, SignStyle,
+};
+#[cfg(feature = "smallstr")]
+pub use small::format_dhms_small;
+pub use subsecond::format_subsecond;
+#[cfg(feature = "time")]
+pub use time_support::{format_time_duration, parse_to_time_duration};
+pub use truncate::{
+    format_adaptive, format_adaptive_with, format_approx, format_capped_plus, format_dhms_coarse,
+    format_dhms_ellipsized, format_dhms_ellipsized_ascii, format_dhms_floor, format_dhms_rounded,
+    format_dhms_rounded_with, format_dhms_truncated, format_dhms_within, format_ns_floor,
+    format_ns_quantized, format_ns_quantized_with, format_ns_rounded, format_ns_rounded_with,
+    format_ns_sig, format_wdhms_capped, RoundMode, ADAPTIVE_THRESHOLDS,
+};
+pub use unit::{dominant_unit, total_in, Unit};
+pub use width::{formatted_len_dhms, formatted_len_ns, formatted_len_ns_bytes};
+
+/// Nanoseconds in a microsecond
+pub const NANOS_PER_US: usize = 1_000;
+/// Nanoseconds in a millisecond
+pub const NANOS_PER_MS: usize = 1_000_000;
+/// Nanoseconds in a second
+pub const NANOS_PER_SECOND: usize = 1_000_000_000;
+/// Seconds in a minute
+pub const SECONDS_PER_MINUTE: usize = 60;
+/// Seconds in an hour
+pub const SECONDS_PER_HOUR: usize = 3_600;
+/// Seconds in a day
+pub const SECONDS_PER_DAY: usize = 86_400;
+/// Seconds in a week
+pub const SECONDS_PER_WEEK: usize = 604_800;
+
+#[deprecated(note = "nanoseconds in a nanosecond, use `1`")]
 pub const NS: usize = 1;
-pub const US: usize = 1_000;
-pub const MS: usize = 1_000_000;
-pub const NANOS: usize = 1_000_000_000;
+#[deprecated(note = "nanoseconds in a microsecond, use `NANOS_PER_US`")]
+pub const US: usize = NANOS_PER_US;
+#[deprecated(note = "nanoseconds in a millisecond, use `NANOS_PER_MS`")]
+pub const MS: usize = NANOS_PER_MS;
+/// Nanoseconds in a second, same as [`NANOS_PER_SECOND`]
+pub const NANOS: usize = NANOS_PER_SECOND;
 pub const SECOND: usize = 1;
-pub const MINUTE: usize = 60;
-pub const HOUR: usize = 3_600;
-pub const DAY: usize = 86_400;
-pub const WEEK: usize = 604_800;
+/// Seconds in a minute, same as [`SECONDS_PER_MINUTE`]
+pub const MINUTE: usize = SECONDS_PER_MINUTE;
+/// Seconds in an hour, same as [`SECONDS_PER_HOUR`]
+pub const HOUR: usize = SECONDS_PER_HOUR;
+/// Seconds in a day, same as [`SECONDS_PER_DAY`]
+pub const DAY: usize = SECONDS_PER_DAY;
+/// Seconds in a week, same as [`SECONDS_PER_WEEK`]
+pub const WEEK: usize = SECONDS_PER_WEEK;
+/// A month is always 30 days
+pub const MONTH: usize = 2_592_000;
+/// A year is always 365 days
+pub const YEAR: usize = 31_536_000;
+
+/// Units of [`format_wdhms`] as `(seconds, suffix)`, largest first
+///
+/// [`format_dhms`] uses the same units without the week.
+///
+/// Example:
+///```
+/// use compound_duration::UNITS;
+///
+/// let seconds = 6_000_000;
+/// let hours = UNITS.iter().find(|(_, suffix)| *suffix == "h").unwrap().0;
+/// assert_eq!(seconds / hours, 1666);
+///```
+pub const UNITS: &[(u64, &str)] = &[
+    (SECONDS_PER_WEEK as u64, "w"),
+    (SECONDS_PER_DAY as u64, "d"),
+    (SECONDS_PER_HOUR as u64, "h"),
+    (SECONDS_PER_MINUTE as u64, "m"),
+    (SECOND as u64, "s"),
+];
+
+/// Units of [`format_ns`] as `(nanoseconds, suffix)`, largest first
+pub const UNITS_NS: &[(u64, &str)] = &[
+    (SECONDS_PER_DAY as u64 * NANOS_PER_SECOND as u64, "d"),
+    (SECONDS_PER_HOUR as u64 * NANOS_PER_SECOND as u64, "h"),
+    (SECONDS_PER_MINUTE as u64 * NANOS_PER_SECOND as u64, "m"),
+    (NANOS_PER_SECOND as u64, "s"),
+    (NANOS_PER_MS as u64, "ms"),
+    (NANOS_PER_US as u64, "\u{b5}s"),
+    (1, "ns"),
+];
+
+const DHMS_FORMATTER: DurationFormatter = DurationFormatter::new();
+const WDHMS_FORMATTER: DurationFormatter = DurationFormatter::new().with_weeks(true);
+const NS_FORMATTER: DurationFormatter = DurationFormatter::new()
+    .with_millis(true)
+    .with_micros(true)
+    .with_nanos(true);
+const NS_ASCII_FORMATTER: DurationFormatter = NS_FORMATTER.ascii(true);
+const MS_FORMATTER: DurationFormatter = DurationFormatter::new().with_millis(true);
+const DHMS_REVERSED_FORMATTER: DurationFormatter = DHMS_FORMATTER.order(Order::SmallestFirst);
+
+/// Error returned when a compound duration string can not be parsed
+///
+/// Variants carrying a `usize` report the byte offset in the input where the
+/// problem was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input string is empty
+    Empty,
+    /// A unit was found without a preceding number, e.g. `"d"`
+    MissingNumber(usize),
+    /// A number was found without a unit suffix, e.g. `"10"`
+    MissingUnit(usize),
+    /// The unit suffix is not known, e.g. `"10x"`
+    UnknownUnit(usize),
+    /// The unit is not in descending order, e.g. `"10m1h"`
+    OutOfOrder(usize),
+    /// The unit is repeated, e.g. `"1d1d"`, only reported by
+    /// [`parse_dhms_strict`], other parsers treat it as [`Self::OutOfOrder`]
+    DuplicateUnit(usize),
+    /// The value does not fit in a `u64`
+    Overflow,
+    /// The value has a fractional part where none is allowed, e.g. `"PT1.5S"`
+    Fractional(usize),
+    /// A fraction has more digits than supported, e.g. ten decimals of a
+    /// second, `"0.0000000001s"`
+    ExcessPrecision(usize),
+    /// The input is not structured as expected, e.g. an ISO 8601 duration
+    /// without the leading `P`
+    InvalidFormat(usize),
+}
+
+/// Error returned when a value can not be converted to a `u64`, for example a
+/// negative number or a `u128` larger than `u64::MAX`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConversionError;
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("value out of range for u64")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+impl ParseError {
+    /// Move the reported byte offset `by` bytes forward
+    pub(crate) const fn shifted(self, by: usize) -> Self {
+        match self {
+            Self::MissingNumber(pos) => Self::MissingNumber(pos + by),
+            Self::MissingUnit(pos) => Self::MissingUnit(pos + by),
+            Self::UnknownUnit(pos) => Self::UnknownUnit(pos + by),
+            Self::OutOfOrder(pos) => Self::OutOfOrder(pos + by),
+            Self::DuplicateUnit(pos) => Self::DuplicateUnit(pos + by),
+            Self::Fractional(pos) => Self::Fractional(pos + by),
+            Self::ExcessPrecision(pos) => Self::ExcessPrecision(pos + by),
+            Self::InvalidFormat(pos) => Self::InvalidFormat(pos + by),
+            Self::Empty | Self::Overflow => self,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty duration"),
+            Self::MissingNumber(pos) => write!(f, "missing number before unit at byte {pos}"),
+            Self::MissingUnit(pos) => write!(f, "missing unit after number at byte {pos}"),
+            Self::UnknownUnit(pos) => write!(f, "unknown unit at byte {pos}"),
+            Self::OutOfOrder(pos) => write!(f, "unit out of order at byte {pos}"),
+            Self::DuplicateUnit(pos) => write!(f, "duplicate unit at byte {pos}"),
+            Self::Overflow => f.write_str("duration out of range for u64"),
+            Self::Fractional(pos) => write!(f, "unexpected fractional part at byte {pos}"),
+            Self::ExcessPrecision(pos) => write!(f, "fraction too precise at byte {pos}"),
+            Self::InvalidFormat(pos) => write!(f, "invalid format at byte {pos}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Convert any integer to `u64`, types wider than `u64` are truncated
+///
+/// Types up to 64 bits are converted without loss, negative values panic.
+fn to_u64<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(value: T) -> u64
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    if core::mem::size_of::<T>() <= core::mem::size_of::<u64>() {
+        value.try_into().unwrap()
+    } else {
+        (value & u64::MAX.try_into().unwrap()).try_into().unwrap()
+    }
+}
 
 /// Convert seconds to compound duration (days, hours, minutes, seconds)
 ///
+/// Any unsigned integer up to 64 bits, like `u8`, `u16` or `u32`, is
+/// formatted in full. Types wider than `u64` are truncated to their lower 64
+/// bits, use [`format_dhms_u128`] to format a `u128` in full.
+///
+/// # Panics
+///
+/// Panics if `seconds` is negative, use [`format_dhms_saturating`] or
+/// [`try_format_dhms`] to handle signed values.
+///
 /// Example:
 ///```
 /// use compound_duration::format_dhms;
@@ -23,61 +303,246 @@ pub const WEEK: usize = 604_800;
 ///
 /// let now = Instant::now();
 /// // do something ...
-/// println!("{}", format_dhms(now.elapsed().as_secs() as usize));
+/// println!("{}", format_dhms(now.elapsed().as_secs()));
 ///
 /// // 69d10h40m
 /// println!("{}", format_dhms(6000000));
 ///```
 #[must_use]
-pub fn format_dhms<T: TryInto<usize> + TryFrom<usize> + BitAnd<Output = T>>(seconds: T) -> String
+pub fn format_dhms<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(seconds: T) -> String
 where
-    <T as TryFrom<usize>>::Error: Debug,
-    <T as TryInto<usize>>::Error: Debug,
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
 {
-    let seconds: usize = if std::mem::size_of::<T>() <= std::mem::size_of::<usize>() {
-        seconds.try_into().unwrap()
-    } else {
-        (seconds & usize::MAX.try_into().unwrap())
-            .try_into()
-            .unwrap()
-    };
+    let seconds = to_u64(seconds);
     let mut compound_duration = String::new();
-    if seconds == 0 {
-        compound_duration.push_str("0s");
-        return compound_duration;
-    }
+    // writing to a String never fails
+    write_dhms(&mut compound_duration, seconds).unwrap();
+    compound_duration
+}
 
-    let mut sec = seconds % DAY;
-    let ds = seconds / DAY;
-    // days
-    if ds != 0 {
-        compound_duration.push_str(format!("{ds}d").as_str());
+/// Convert seconds to compound duration (days, hours, minutes, seconds),
+/// borrowing instead of allocating for zero
+///
+/// Same output as [`format_dhms`], but zero returns a borrowed `"0s"` so
+/// formatting lots of zero durations doesn't allocate.
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_cow;
+/// use std::borrow::Cow;
+///
+/// assert_eq!(format_dhms_cow(0), Cow::Borrowed("0s"));
+/// assert_eq!(format_dhms_cow(6_000_000), "69d10h40m");
+///```
+#[must_use]
+pub fn format_dhms_cow<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+) -> Cow<'static, str>
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    match to_u64(seconds) {
+        0 => Cow::Borrowed("0s"),
+        seconds => Cow::Owned(format_dhms(seconds)),
     }
+}
 
-    // hours
-    let hs = sec / HOUR;
-    sec %= HOUR;
-    if hs != 0 {
-        compound_duration.push_str(format!("{hs}h").as_str());
+/// Convert seconds to compound duration (days, hours, minutes, seconds) with
+/// zero shown in `zero_unit`
+///
+/// Zero is `"0"` followed by the suffix of `zero_unit`, e.g. `"0m"`, anything
+/// else is the same as [`format_dhms`].
+///
+/// Example:
+///```
+/// use compound_duration::{format_dhms_zero_as, Unit};
+///
+/// assert_eq!(format_dhms_zero_as(0, Unit::Minute), "0m");
+/// assert_eq!(format_dhms_zero_as(30, Unit::Minute), "30s");
+///```
+#[must_use]
+pub fn format_dhms_zero_as<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    zero_unit: Unit,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    match to_u64(seconds) {
+        0 => format!("0{}", zero_unit.suffix()),
+        seconds => format_dhms(seconds),
     }
+}
 
-    // minutes
-    let ms = sec / MINUTE;
-    sec %= MINUTE;
-    if ms != 0 {
-        compound_duration.push_str(format!("{ms}m").as_str());
-    }
+/// Convert seconds to compound duration (days, hours, minutes, seconds) without
+/// panicking or truncating
+///
+/// # Errors
+///
+/// Returns a [`ConversionError`] if `seconds` can't be represented as a `u64`.
+///
+/// Example:
+///```
+/// use compound_duration::{try_format_dhms, ConversionError};
+///
+/// assert_eq!(try_format_dhms(6_000_000), Ok(String::from("69d10h40m")));
+/// assert_eq!(try_format_dhms(-1), Err(ConversionError));
+///```
+pub fn try_format_dhms<T: TryInto<u64>>(seconds: T) -> Result<String, ConversionError> {
+    let seconds = seconds.try_into().map_err(|_| ConversionError)?;
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_dhms(&mut compound_duration, seconds).unwrap();
+    Ok(compound_duration)
+}
 
-    // seconds
-    if sec != 0 {
-        compound_duration.push_str(format!("{sec}s").as_str());
+/// Convert seconds to compound duration (days, hours, minutes, seconds)
+/// clamping values out of the `u64` range instead of panicking
+///
+/// Negative values are clamped to zero and values larger than `u64::MAX` to
+/// `u64::MAX`, unlike [`format_dhms`] that panics on negative values and
+/// truncates wider types to their lower 64 bits.
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_saturating;
+///
+/// assert_eq!(format_dhms_saturating(-1), "0s");
+/// assert_eq!(format_dhms_saturating(u128::MAX), "213503982334601d7h15s");
+/// assert_eq!(format_dhms_saturating(61_i8), "1m1s");
+///```
+#[must_use]
+pub fn format_dhms_saturating<T: TryInto<u64> + PartialOrd + Default>(seconds: T) -> String {
+    let negative = seconds < T::default();
+    let seconds = seconds
+        .try_into()
+        .unwrap_or(if negative { 0 } else { u64::MAX });
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_dhms(&mut compound_duration, seconds).unwrap();
+    compound_duration
+}
+
+/// Write seconds as compound duration (days, hours, minutes, seconds)
+///
+/// Same output as [`format_dhms`] but appended to an existing [`fmt::Write`].
+///
+/// # Errors
+///
+/// Returns an error only if writing to `out` fails.
+///
+/// Example:
+///```
+/// use compound_duration::write_dhms;
+///
+/// let mut buf = String::from("uptime: ");
+/// write_dhms(&mut buf, 6_000_000).unwrap();
+/// assert_eq!(buf, "uptime: 69d10h40m");
+///```
+pub fn write_dhms<W: fmt::Write>(out: &mut W, seconds: u64) -> fmt::Result {
+    if seconds == 0 {
+        return out.write_str("0s");
     }
+    for (value, suffix) in dhms_parts(seconds).iter().filter(|(value, _)| *value != 0) {
+        write!(out, "{value}{suffix}")?;
+    }
+    Ok(())
+}
 
-    compound_duration
+/// Convert seconds to compound duration (days, hours, minutes, seconds) with
+/// `separator` between the units
+///
+/// An empty separator gives the same output as [`format_dhms`].
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_sep;
+///
+/// assert_eq!(format_dhms_sep(6_000_000, " "), "69d 10h 40m");
+/// assert_eq!(format_dhms_sep(6_000_000, ":"), "69d:10h:40m");
+///```
+#[must_use]
+pub fn format_dhms_sep<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    separator: &str,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    DHMS_FORMATTER.separator(separator).format(to_u64(seconds))
+}
+
+/// Spacing between the units used by [`format_dhms_spaced`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Spacing {
+    /// No space, `"69d10h40m"`
+    #[default]
+    None,
+    /// A single space between units, `"69d 10h 40m"`
+    BetweenUnits,
+}
+
+/// Convert seconds to compound duration (days, hours, minutes, seconds) with
+/// or without spaces between the units
+///
+/// [`Spacing::None`] gives the same output as [`format_dhms`], zero is always
+/// `"0s"`.
+///
+/// Example:
+///```
+/// use compound_duration::{format_dhms_spaced, Spacing};
+///
+/// assert_eq!(format_dhms_spaced(6_000_000, Spacing::BetweenUnits), "69d 10h 40m");
+/// assert_eq!(format_dhms_spaced(6_000_000, Spacing::None), "69d10h40m");
+///```
+#[must_use]
+pub fn format_dhms_spaced<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    spacing: Spacing,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let separator = match spacing {
+        Spacing::None => "",
+        Spacing::BetweenUnits => " ",
+    };
+    format_dhms_sep(seconds, separator)
+}
+
+/// Convert seconds to compound duration (days, hours, minutes, seconds) with
+/// the smallest unit first
+///
+/// Same units as [`format_dhms`] in the opposite order, zero is `"0s"`.
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_reversed;
+///
+/// assert_eq!(format_dhms_reversed(6_000_000), "40m10h69d");
+/// assert_eq!(format_dhms_reversed(0), "0s");
+///```
+#[must_use]
+pub fn format_dhms_reversed<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    DHMS_REVERSED_FORMATTER.format(to_u64(seconds))
 }
 
 /// Convert seconds to compound duration (week, days, hours, minutes, seconds)
 ///
+/// Types wider than `u64` are truncated to their lower 64 bits, use
+/// [`format_wdhms_u128`] to format a `u128` in full.
+///
 /// Example:
 ///```
 /// use compound_duration::format_wdhms;
@@ -85,61 +550,70 @@ where
 /// println!("{}", format_wdhms(6000000));
 ///```
 #[must_use]
-pub fn format_wdhms<T: TryInto<usize> + TryFrom<usize> + BitAnd<Output = T>>(seconds: T) -> String
+pub fn format_wdhms<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(seconds: T) -> String
 where
-    <T as TryFrom<usize>>::Error: Debug,
-    <T as TryInto<usize>>::Error: Debug,
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
 {
-    let seconds: usize = if std::mem::size_of::<T>() <= std::mem::size_of::<usize>() {
-        seconds.try_into().unwrap()
-    } else {
-        (seconds & usize::MAX.try_into().unwrap())
-            .try_into()
-            .unwrap()
-    };
+    let seconds = to_u64(seconds);
     let mut compound_duration = String::new();
-    if seconds == 0 {
-        compound_duration.push_str("0s");
-        return compound_duration;
-    }
-
-    let mut sec = seconds % WEEK;
-    // weeks
-    let ws = seconds / WEEK;
-    if ws != 0 {
-        compound_duration.push_str(format!("{ws}w").as_str());
-    }
-
-    // days
-    let ds = sec / DAY;
-    sec %= DAY;
-    if ds != 0 {
-        compound_duration.push_str(format!("{ds}d").as_str());
-    }
-
-    // hours
-    let hs = sec / HOUR;
-    sec %= HOUR;
-    if hs != 0 {
-        compound_duration.push_str(format!("{hs}h").as_str());
-    }
+    // writing to a String never fails
+    write_wdhms(&mut compound_duration, seconds).unwrap();
+    compound_duration
+}
 
-    // minutes
-    let ms = sec / MINUTE;
-    sec %= MINUTE;
-    if ms != 0 {
-        compound_duration.push_str(format!("{ms}m").as_str());
+/// Write seconds as compound duration (week, days, hours, minutes, seconds)
+///
+/// Same output as [`format_wdhms`] but appended to an existing [`fmt::Write`].
+///
+/// # Errors
+///
+/// Returns an error only if writing to `out` fails.
+///
+/// Example:
+///```
+/// use compound_duration::write_wdhms;
+///
+/// let mut buf = String::new();
+/// write_wdhms(&mut buf, 6_000_000).unwrap();
+/// assert_eq!(buf, "9w6d10h40m");
+///```
+pub fn write_wdhms<W: fmt::Write>(out: &mut W, seconds: u64) -> fmt::Result {
+    for (value, suffix) in iter_wdhms(seconds) {
+        write!(out, "{value}{suffix}")?;
     }
+    Ok(())
+}
 
-    // seconds
-    if sec != 0 {
-        compound_duration.push_str(format!("{sec}s").as_str());
-    }
+/// Split seconds into `(value, suffix)` pairs for days, hours, minutes and
+/// seconds, the weeks of [`split_wdhms`] are folded into the days
+pub(crate) const fn dhms_parts(seconds: u64) -> [(u64, &'static str); 4] {
+    let (weeks, days, hours, minutes, seconds) = split_wdhms(seconds);
+    [
+        (weeks * 7 + days, "d"),
+        (hours, "h"),
+        (minutes, "m"),
+        (seconds, "s"),
+    ]
+}
 
-    compound_duration
+/// Split nanoseconds under a second into `(value, unit)` pairs for ms, µs and
+/// ns, anything from one second up is ignored
+pub(crate) const fn subsec_parts(nanos: u64) -> [(u64, Unit); 3] {
+    let nanos = nanos % NANOS_PER_SECOND as u64;
+    let (ms, us) = (NANOS_PER_MS as u64, NANOS_PER_US as u64);
+    [
+        (nanos / ms, Unit::Milli),
+        (nanos % ms / us, Unit::Micro),
+        (nanos % us, Unit::Nano),
+    ]
 }
 
-/// Convert seconds to compound duration (days, hours, minutes, seconds, ms, µs, ns)
+/// Convert nanoseconds to compound duration (days, hours, minutes, seconds, ms, µs, ns)
+///
+/// Types wider than `u64` are truncated to their lower 64 bits, use
+/// [`format_ns_u128`] to format a `u128` in full or [`format_ns_checked`] to
+/// reject values that don't fit.
 ///
 /// Example:
 ///```
@@ -147,78 +621,356 @@ where
 /// use std::time::Instant;
 ///
 /// let now = Instant::now();
-/// println!("{}", format_ns(now.elapsed().as_nanos() as usize));
+/// println!("{}", format_ns(now.elapsed().as_nanos() as u64));
 ///```
 #[must_use]
-pub fn format_ns<T: TryInto<usize> + TryFrom<usize> + BitAnd<Output = T>>(nanos: T) -> String
+pub fn format_ns<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(nanos: T) -> String
 where
-    <T as TryFrom<usize>>::Error: Debug,
-    <T as TryInto<usize>>::Error: Debug,
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
 {
-    let nanos: usize = if std::mem::size_of::<T>() <= std::mem::size_of::<usize>() {
-        nanos.try_into().unwrap()
-    } else {
-        (nanos & usize::MAX.try_into().unwrap()).try_into().unwrap()
-    };
+    let nanos = to_u64(nanos);
     let mut compound_duration = String::new();
-    if nanos == 0 {
-        compound_duration.push_str("0ns");
-        return compound_duration;
-    }
+    // writing to a String never fails
+    write_ns(&mut compound_duration, nanos).unwrap();
+    compound_duration
+}
 
-    let mut ns = nanos % (DAY * NANOS);
-    let d_ns = nanos / (DAY * NANOS);
-    // days
-    if d_ns != 0 {
-        compound_duration.push_str(format!("{d_ns}d").as_str());
-    }
+/// Convert nanoseconds to compound duration (days, hours, minutes, seconds, ms, us, ns)
+/// using only ASCII
+///
+/// Same output as [`format_ns`] except microseconds are written as `us`
+/// instead of `µs`, for terminals and logs that can't handle Unicode.
+/// [`parse_ns`] accepts both.
+///
+/// Example:
+///```
+/// use compound_duration::format_ns_ascii;
+///
+/// assert_eq!(format_ns_ascii(3_000_129_723_u64), "3s129us723ns");
+///```
+#[must_use]
+pub fn format_ns_ascii<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(nanos: T) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    NS_ASCII_FORMATTER.format_nanos(to_u64(nanos))
+}
 
-    // hours
-    let h_ns = ns / (HOUR * NANOS);
-    ns %= HOUR * NANOS;
-    if h_ns != 0 {
-        compound_duration.push_str(format!("{h_ns}h").as_str());
-    }
+/// Write nanoseconds as compound duration (days, hours, minutes, seconds, ms, µs, ns)
+///
+/// Same output as [`format_ns`] but appended to an existing [`fmt::Write`],
+/// no allocation is done so a single buffer can be reused across calls.
+///
+/// # Errors
+///
+/// Returns an error only if writing to `out` fails.
+///
+/// Example:
+///```
+/// use compound_duration::write_ns;
+///
+/// let mut buf = String::new();
+/// write_ns(&mut buf, 3_000_129_723).unwrap();
+/// assert_eq!(buf, "3s129\u{b5}s723ns");
+///```
+pub fn write_ns<W: fmt::Write>(out: &mut W, nanos: u64) -> fmt::Result {
+    NS_FORMATTER.write_nanos(out, nanos)
+}
 
-    // minutes
-    let minutes_ns = ns / (MINUTE * NANOS);
-    ns %= MINUTE * NANOS;
-    if minutes_ns != 0 {
-        compound_duration.push_str(format!("{minutes_ns}m").as_str());
-    }
+/// Convert milliseconds to compound duration (days, hours, minutes, seconds, ms)
+///
+/// For APIs that count in milliseconds, the smallest unit is the millisecond,
+/// anything below it can't be represented and microseconds and nanoseconds
+/// are never shown. Zero is `"0ms"`.
+///
+/// Example:
+///```
+/// use compound_duration::format_ms_input;
+///
+/// assert_eq!(format_ms_input(90_061_500), "1d1h1m1s500ms");
+/// assert_eq!(format_ms_input(1000), "1s");
+///```
+#[must_use]
+pub fn format_ms_input<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(millis: T) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    MS_FORMATTER
+        .write_total(
+            &mut compound_duration,
+            u128::from(to_u64(millis)),
+            NANOS_PER_MS as u128,
+        )
+        .unwrap();
+    compound_duration
+}
 
-    // seconds
-    let sec_ns = ns / (SECOND * NANOS);
-    ns %= SECOND * NANOS;
-    if sec_ns != 0 {
-        compound_duration.push_str(format!("{sec_ns}s").as_str());
-    }
+/// Convert seconds to compound duration (days, hours, minutes, seconds) using
+/// the full width of a `u128`
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_u128;
+///
+/// let seconds = u128::from(u64::MAX) + 1;
+/// assert_eq!(format_dhms_u128(seconds), "213503982334601d7h16s");
+///```
+#[must_use]
+pub fn format_dhms_u128(seconds: u128) -> String {
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    DHMS_FORMATTER
+        .write_total(&mut compound_duration, seconds, NANOS_PER_SECOND as u128)
+        .unwrap();
+    compound_duration
+}
 
-    // milliseconds
-    let ms_ns = ns / MS;
-    ns %= MS;
-    if ms_ns != 0 {
-        compound_duration.push_str(format!("{ms_ns}ms").as_str());
-    }
+/// Convert seconds to compound duration (week, days, hours, minutes, seconds)
+/// using the full width of a `u128`
+///
+/// Example:
+///```
+/// use compound_duration::format_wdhms_u128;
+///
+/// let seconds = u128::from(u64::MAX) + 1;
+/// assert_eq!(format_wdhms_u128(seconds), "30500568904943w7h16s");
+///```
+#[must_use]
+pub fn format_wdhms_u128(seconds: u128) -> String {
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    WDHMS_FORMATTER
+        .write_total(&mut compound_duration, seconds, NANOS_PER_SECOND as u128)
+        .unwrap();
+    compound_duration
+}
 
-    // microseconds
-    let micro_ns = ns / US;
-    ns %= US;
-    if micro_ns != 0 {
-        compound_duration.push_str(format!("{micro_ns}\u{b5}s").as_str());
-    }
+/// Convert nanoseconds to compound duration (days, hours, minutes, seconds,
+/// ms, µs, ns) using the full width of a `u128`
+///
+/// Example:
+///```
+/// use compound_duration::format_ns_u128;
+/// use std::time::Instant;
+///
+/// let now = Instant::now();
+/// println!("{}", format_ns_u128(now.elapsed().as_nanos()));
+///```
+#[must_use]
+pub fn format_ns_u128(nanos: u128) -> String {
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    NS_FORMATTER
+        .write_total(&mut compound_duration, nanos, 1)
+        .unwrap();
+    compound_duration
+}
 
-    // nanoseconds
-    if ns != 0 {
-        compound_duration.push_str(format!("{ns}ns").as_str());
-    }
+/// Convert nanoseconds to compound duration (days, hours, minutes, seconds,
+/// ms, µs, ns), rejecting values larger than `u64::MAX`
+///
+/// Unlike [`format_ns`] nothing is truncated, a `u128` like the one returned by
+/// [`Duration::as_nanos`] that doesn't fit in a `u64` is an error.
+///
+/// # Errors
+///
+/// Returns a [`ConversionError`] if `nanos` is larger than `u64::MAX`.
+///
+/// Example:
+///```
+/// use compound_duration::{format_ns_checked, ConversionError};
+///
+/// assert_eq!(format_ns_checked(3_000_129_723), Ok(String::from("3s129µs723ns")));
+/// assert_eq!(format_ns_checked(u128::from(u64::MAX) + 1), Err(ConversionError));
+///```
+pub fn format_ns_checked(nanos: u128) -> Result<String, ConversionError> {
+    let nanos = u64::try_from(nanos).map_err(|_| ConversionError)?;
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_ns(&mut compound_duration, nanos).unwrap();
+    Ok(compound_duration)
+}
 
-    compound_duration
+/// Convert a [`Duration`] to compound duration (days, hours, minutes, seconds, ms, µs, ns)
+///
+/// Unlike passing `as_nanos()` to [`format_ns`], this never truncates, any
+/// `Duration` is formatted in full even when it exceeds `u64::MAX` nanoseconds.
+///
+/// Example:
+///```
+/// use compound_duration::format_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(format_duration(Duration::new(61, 1_000_001)), "1m1s1ms1ns");
+///```
+#[must_use]
+pub fn format_duration(duration: Duration) -> String {
+    format_ns_u128(duration.as_nanos())
+}
+
+/// Convert a [`Duration`] to compound duration (days, hours, minutes, seconds)
+///
+/// The fractional part of a second is discarded.
+///
+/// Example:
+///```
+/// use compound_duration::format_duration_dhms;
+/// use std::time::Duration;
+///
+/// assert_eq!(format_duration_dhms(Duration::new(61, 1_000_001)), "1m1s");
+///```
+#[must_use]
+pub fn format_duration_dhms(duration: Duration) -> String {
+    format_dhms(duration.as_secs())
+}
+
+/// Same as [`format_duration`] taking the [`Duration`] by reference
+///
+/// Example:
+///```
+/// use compound_duration::format_duration_ref;
+/// use std::time::Duration;
+///
+/// let durations = vec![Duration::from_millis(1500), Duration::from_secs(61)];
+/// let formatted: Vec<String> = durations.iter().map(format_duration_ref).collect();
+/// assert_eq!(formatted, ["1s500ms", "1m1s"]);
+///```
+#[must_use]
+pub fn format_duration_ref(duration: &Duration) -> String {
+    format_duration(*duration)
+}
+
+/// Same as [`format_duration_dhms`] taking the [`Duration`] by reference
+///
+/// Example:
+///```
+/// use compound_duration::format_duration_dhms_ref;
+/// use std::time::Duration;
+///
+/// let durations = vec![Duration::from_millis(1500), Duration::from_secs(61)];
+/// let formatted: Vec<String> = durations.iter().map(format_duration_dhms_ref).collect();
+/// assert_eq!(formatted, ["1s", "1m1s"]);
+///```
+#[must_use]
+pub fn format_duration_dhms_ref(duration: &Duration) -> String {
+    format_duration_dhms(*duration)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{format_dhms, format_ns, format_wdhms};
+    use super::{
+        components_wdhms, format_dhms, format_dhms_cow, format_dhms_reversed,
+        format_dhms_saturating, format_dhms_sep, format_dhms_spaced, format_dhms_u128,
+        format_dhms_zero_as, format_duration, format_duration_dhms, format_duration_dhms_ref,
+        format_duration_ref, format_ms_input, format_ns, format_ns_ascii, format_ns_checked,
+        format_ns_u128, format_wdhms, format_wdhms_u128, try_format_dhms, write_dhms, write_ns,
+        write_wdhms, ConversionError, Spacing, Unit,
+    };
+    use alloc::borrow::Cow;
+    use alloc::format;
+    use alloc::string::{String, ToString};
+    use core::time::Duration;
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_constants() {
+        use super::{
+            DAY, HOUR, MINUTE, MS, NANOS, NANOS_PER_MS, NANOS_PER_SECOND, NANOS_PER_US, NS,
+            SECONDS_PER_DAY, SECONDS_PER_HOUR, SECONDS_PER_MINUTE, SECONDS_PER_WEEK, US, WEEK,
+        };
+
+        assert_eq!(NANOS_PER_US, 1_000);
+        assert_eq!(NANOS_PER_MS, 1_000 * NANOS_PER_US);
+        assert_eq!(NANOS_PER_SECOND, 1_000 * NANOS_PER_MS);
+        assert_eq!(SECONDS_PER_MINUTE, 60);
+        assert_eq!(SECONDS_PER_HOUR, 60 * SECONDS_PER_MINUTE);
+        assert_eq!(SECONDS_PER_DAY, 24 * SECONDS_PER_HOUR);
+        assert_eq!(SECONDS_PER_WEEK, 7 * SECONDS_PER_DAY);
+
+        // the old names keep their values
+        assert_eq!(NS, 1);
+        assert_eq!(US, NANOS_PER_US);
+        assert_eq!(MS, NANOS_PER_MS);
+        assert_eq!(NANOS, NANOS_PER_SECOND);
+        assert_eq!(MINUTE, SECONDS_PER_MINUTE);
+        assert_eq!(HOUR, SECONDS_PER_HOUR);
+        assert_eq!(DAY, SECONDS_PER_DAY);
+        assert_eq!(WEEK, SECONDS_PER_WEEK);
+    }
+
+    #[test]
+    fn test_units() {
+        use super::{UNITS, UNITS_NS};
+
+        for table in [UNITS, UNITS_NS] {
+            for pair in table.windows(2) {
+                assert!(pair[0].0 > pair[1].0);
+                assert_eq!(pair[0].0 % pair[1].0, 0);
+            }
+        }
+        assert_eq!(UNITS.last(), Some(&(1, "s")));
+        assert_eq!(UNITS_NS.last(), Some(&(1, "ns")));
+
+        // the tables match the Unit sizes and suffixes
+        for &(seconds, suffix) in UNITS {
+            let unit = Unit::ALL.iter().find(|unit| unit.suffix() == suffix);
+            assert_eq!(
+                unit.map(|unit| unit.in_nanos() / 1_000_000_000),
+                Some(seconds)
+            );
+        }
+        for &(nanos, suffix) in UNITS_NS {
+            let unit = Unit::ALL.iter().find(|unit| unit.suffix() == suffix);
+            assert_eq!(unit.map(|unit| unit.in_nanos()), Some(nanos));
+        }
+
+        // and give the same output as the formatters
+        let render = |table: &[(u64, &str)], mut rest: u64| -> String {
+            let mut out = String::new();
+            for &(size, suffix) in table {
+                if rest >= size {
+                    out.push_str(&alloc::format!("{}{suffix}", rest / size));
+                    rest %= size;
+                }
+            }
+            out
+        };
+        for value in (1..1000).map(|i| i * (u64::MAX / 1000)) {
+            assert_eq!(render(UNITS, value), format_wdhms(value));
+            assert_eq!(render(UNITS_NS, value), format_ns(value));
+        }
+    }
+
+    #[test]
+    fn test_format_dhms_cow() {
+        assert!(matches!(format_dhms_cow(0), Cow::Borrowed("0s")));
+        assert!(matches!(format_dhms_cow(0_u128), Cow::Borrowed("0s")));
+        assert!(matches!(format_dhms_cow(1), Cow::Owned(_)));
+        assert_eq!(format_dhms_cow(61), "1m1s");
+        for seconds in (0..1_000_000).step_by(997) {
+            assert_eq!(format_dhms_cow(seconds), format_dhms(seconds));
+        }
+        assert_eq!(format_dhms_cow(u64::MAX), format_dhms(u64::MAX));
+    }
+
+    #[test]
+    fn test_format_dhms_zero_as() {
+        assert_eq!(format_dhms_zero_as(0, Unit::Minute), "0m");
+        assert_eq!(format_dhms_zero_as(0, Unit::Second), "0s");
+        assert_eq!(format_dhms_zero_as(0, Unit::Week), "0w");
+        assert_eq!(format_dhms_zero_as(0, Unit::Micro), "0\u{b5}s");
+        assert_eq!(format_dhms_zero_as(0_u128, Unit::Hour), "0h");
+        for &unit in &Unit::ALL {
+            for seconds in (1..1_000_000).step_by(997) {
+                assert_eq!(format_dhms_zero_as(seconds, unit), format_dhms(seconds));
+            }
+        }
+    }
 
     #[test]
     fn test_format_dhms() {
@@ -234,6 +986,160 @@ mod tests {
         assert_eq!(format_dhms(4_294_967_295_usize), "49710d6h28m15s");
     }
 
+    #[test]
+    fn test_format_small_types() {
+        assert_eq!(format_dhms(200_u8), "3m20s");
+        assert_eq!(format_dhms(u8::MAX), "4m15s");
+        assert_eq!(format_dhms(50_000_u16), "13h53m20s");
+        assert_eq!(format_dhms(u16::MAX), "18h12m15s");
+        assert_eq!(format_dhms(4_000_000_000_u32), "46296d7h6m40s");
+        assert_eq!(format_dhms(u32::MAX), "49710d6h28m15s");
+        assert_eq!(format_dhms(127_i8), "2m7s");
+        assert_eq!(format_dhms(i16::MAX), "9h6m7s");
+        assert_eq!(format_dhms(i32::MAX), "24855d3h14m7s");
+        assert_eq!(format_dhms(u64::MAX), "213503982334601d7h15s");
+
+        assert_eq!(format_wdhms(200_u8), "3m20s");
+        assert_eq!(format_wdhms(50_000_u16), "13h53m20s");
+        assert_eq!(format_wdhms(4_000_000_000_u32), "6613w5d7h6m40s");
+        assert_eq!(format_ns(200_u8), "200ns");
+        assert_eq!(format_ns(50_000_u16), "50\u{b5}s");
+        assert_eq!(format_ns(4_000_000_000_u32), "4s");
+
+        // nothing is truncated below 64 bits
+        for seconds in 0..=u16::MAX {
+            assert_eq!(format_dhms(seconds), format_dhms(u64::from(seconds)));
+        }
+        for seconds in (0..=u32::MAX).step_by(65_537) {
+            assert_eq!(format_dhms(seconds), format_dhms(u64::from(seconds)));
+        }
+
+        // wider types keep their lower 64 bits
+        assert_eq!(format_dhms(u128::from(u64::MAX) + 61), "1m");
+        assert_eq!(format_dhms(-1_i128), format_dhms(u64::MAX));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_format_dhms_negative() {
+        let _ = format_dhms(-1_i32);
+    }
+
+    #[test]
+    fn test_try_format_dhms() {
+        assert_eq!(try_format_dhms(0), Ok(String::from("0s")));
+        assert_eq!(
+            try_format_dhms(6_000_000_u32),
+            Ok(String::from("69d10h40m"))
+        );
+        assert_eq!(try_format_dhms(61_i8), Ok(String::from("1m1s")));
+        assert_eq!(try_format_dhms(u64::MAX), Ok(format_dhms(u64::MAX)));
+        assert_eq!(
+            try_format_dhms(u128::from(u64::MAX)),
+            Ok(format_dhms(u64::MAX))
+        );
+        assert_eq!(try_format_dhms(-1_i32), Err(ConversionError));
+        assert_eq!(try_format_dhms(i64::MIN), Err(ConversionError));
+        assert_eq!(
+            try_format_dhms(u128::from(u64::MAX) + 1),
+            Err(ConversionError)
+        );
+        assert_eq!(ConversionError.to_string(), "value out of range for u64");
+    }
+
+    #[test]
+    fn test_format_dhms_saturating() {
+        assert_eq!(format_dhms_saturating(0), "0s");
+        assert_eq!(format_dhms_saturating(61_i8), "1m1s");
+        assert_eq!(format_dhms_saturating(6_000_000_u32), "69d10h40m");
+        assert_eq!(format_dhms_saturating(-1), "0s");
+        assert_eq!(format_dhms_saturating(i32::MIN), "0s");
+        assert_eq!(format_dhms_saturating(i64::MIN), "0s");
+        assert_eq!(format_dhms_saturating(i128::MIN), "0s");
+        assert_eq!(format_dhms_saturating(i32::MAX), format_dhms(i32::MAX));
+        assert_eq!(format_dhms_saturating(i64::MAX), format_dhms(i64::MAX));
+        assert_eq!(format_dhms_saturating(u64::MAX), format_dhms(u64::MAX));
+        assert_eq!(
+            format_dhms_saturating(u128::from(u64::MAX)),
+            format_dhms(u64::MAX)
+        );
+
+        // out of range values don't wrap
+        assert_eq!(
+            format_dhms_saturating(u128::from(u64::MAX) + 1),
+            format_dhms(u64::MAX)
+        );
+        assert_eq!(format_dhms_saturating(u128::MAX), format_dhms(u64::MAX));
+        assert_eq!(format_dhms_saturating(i128::MAX), format_dhms(u64::MAX));
+
+        for seconds in (-1_000_000..1_000_000_i64).step_by(997) {
+            assert_eq!(format_dhms_saturating(seconds), format_dhms(seconds.max(0)));
+        }
+    }
+
+    #[test]
+    fn test_write_dhms() {
+        let mut buf = String::new();
+        write_dhms(&mut buf, 0).unwrap();
+        assert_eq!(buf, "0s");
+        buf.clear();
+        write_dhms(&mut buf, 6_000_000).unwrap();
+        assert_eq!(buf, "69d10h40m");
+        write_dhms(&mut buf, 7259).unwrap();
+        assert_eq!(buf, "69d10h40m2h59s");
+        for seconds in (0..1_000_000).step_by(997) {
+            buf.clear();
+            write_dhms(&mut buf, seconds).unwrap();
+            assert_eq!(buf, format_dhms(seconds));
+        }
+    }
+
+    #[test]
+    fn test_format_dhms_sep() {
+        assert_eq!(format_dhms_sep(0, " "), "0s");
+        assert_eq!(format_dhms_sep(30, ", "), "30s");
+        assert_eq!(format_dhms_sep(61, " "), "1m 1s");
+        assert_eq!(format_dhms_sep(6_000_000, " "), "69d 10h 40m");
+        assert_eq!(format_dhms_sep(6_000_000, ":"), "69d:10h:40m");
+        assert_eq!(format_dhms_sep(86401, " - "), "1d - 1s");
+        for seconds in (0..1_000_000).step_by(997) {
+            assert_eq!(format_dhms_sep(seconds, ""), format_dhms(seconds));
+        }
+    }
+
+    #[test]
+    fn test_format_dhms_spaced() {
+        assert_eq!(format_dhms_spaced(0, Spacing::None), "0s");
+        assert_eq!(format_dhms_spaced(0, Spacing::BetweenUnits), "0s");
+        assert_eq!(format_dhms_spaced(30, Spacing::BetweenUnits), "30s");
+        assert_eq!(format_dhms_spaced(61, Spacing::BetweenUnits), "1m 1s");
+        assert_eq!(format_dhms_spaced(6_000_000, Spacing::None), "69d10h40m");
+        assert_eq!(
+            format_dhms_spaced(6_000_000, Spacing::BetweenUnits),
+            "69d 10h 40m"
+        );
+        assert_eq!(Spacing::default(), Spacing::None);
+        for seconds in (0..1_000_000).step_by(997) {
+            assert_eq!(
+                format_dhms_spaced(seconds, Spacing::None),
+                format_dhms(seconds)
+            );
+            assert_eq!(
+                format_dhms_spaced(seconds, Spacing::BetweenUnits),
+                format_dhms_sep(seconds, " ")
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_dhms_reversed() {
+        assert_eq!(format_dhms_reversed(0), "0s");
+        assert_eq!(format_dhms_reversed(1), "1s");
+        assert_eq!(format_dhms_reversed(61), "1s1m");
+        assert_eq!(format_dhms_reversed(6_000_000), "40m10h69d");
+        assert_eq!(format_dhms_reversed(4_294_967_295_u32), "15s28m6h49710d");
+    }
+
     #[test]
     fn test_format_wdhms() {
         assert_eq!(format_wdhms(0), "0s");
@@ -248,6 +1154,73 @@ mod tests {
         assert_eq!(format_wdhms(4_294_967_295_usize), "7101w3d6h28m15s");
     }
 
+    #[test]
+    fn test_write_wdhms() {
+        let mut buf = String::new();
+        write_wdhms(&mut buf, 0).unwrap();
+        assert_eq!(buf, "0s");
+        buf.clear();
+        write_wdhms(&mut buf, 6_000_000).unwrap();
+        assert_eq!(buf, "9w6d10h40m");
+        write_wdhms(&mut buf, 61).unwrap();
+        assert_eq!(buf, "9w6d10h40m1m1s");
+        for seconds in (0..10_000_000).step_by(9973) {
+            buf.clear();
+            write_wdhms(&mut buf, seconds).unwrap();
+            assert_eq!(buf, format_wdhms(seconds));
+        }
+    }
+
+    #[test]
+    fn test_format_wdhms_components() {
+        let values =
+            (0..10_000_000_u64)
+                .step_by(9973)
+                .chain([604_799, 604_800, u64::MAX - 1, u64::MAX]);
+        for seconds in values {
+            let c = components_wdhms(seconds);
+            let fields = [
+                (c.weeks, "w"),
+                (c.days, "d"),
+                (c.hours, "h"),
+                (c.minutes, "m"),
+                (c.seconds, "s"),
+            ];
+            let expected: String = fields
+                .iter()
+                .filter(|(value, _)| *value != 0)
+                .map(|(value, suffix)| format!("{value}{suffix}"))
+                .collect();
+            let expected = if expected.is_empty() {
+                "0s".to_string()
+            } else {
+                expected
+            };
+            assert_eq!(format_wdhms(seconds), expected);
+            assert_eq!(format_wdhms(seconds), format_wdhms_u128(seconds.into()));
+            assert_eq!(format_dhms(seconds), format_dhms_u128(seconds.into()));
+        }
+    }
+
+    #[test]
+    fn test_format_ns_ascii() {
+        assert_eq!(format_ns_ascii(0), "0ns");
+        assert_eq!(format_ns_ascii(1000), "1us");
+        assert_eq!(format_ns_ascii(3_000_129_723_usize), "3s129us723ns");
+        assert_eq!(
+            format_ns_ascii(100_000_000_010_100_001_usize),
+            "1157d9h46m40s10ms100us1ns"
+        );
+        for &nanos in &[0_u64, 1, 999, 1000, 1_001_001, 3_000_129_723, u64::MAX] {
+            assert_eq!(
+                format_ns_ascii(nanos),
+                format_ns(nanos).replace('\u{b5}', "u")
+            );
+            assert!(format_ns_ascii(nanos).is_ascii());
+            assert_eq!(crate::parse_ns(&format_ns_ascii(nanos)), Ok(nanos));
+        }
+    }
+
     #[test]
     fn test_format_ns() {
         assert_eq!(format_ns(3_000_129_723_usize), "3s129\u{b5}s723ns");
@@ -287,4 +1260,168 @@ mod tests {
         assert_eq!(format_ns(1), "1ns");
         assert_eq!(format_ns(0), "0ns");
     }
+
+    #[test]
+    fn test_write_ns() {
+        let mut buf = String::new();
+        write_ns(&mut buf, 0).unwrap();
+        assert_eq!(buf, "0ns");
+        buf.clear();
+        write_ns(&mut buf, 100_000_000_010_100_001).unwrap();
+        assert_eq!(buf, "1157d9h46m40s10ms100\u{b5}s1ns");
+
+        // appends to the existing content
+        write_ns(&mut buf, 1_000_001).unwrap();
+        assert_eq!(buf, "1157d9h46m40s10ms100\u{b5}s1ns1ms1ns");
+
+        // reusing the buffer does not reallocate
+        buf.clear();
+        let capacity = buf.capacity();
+        for nanos in 0..10_000 {
+            buf.clear();
+            write_ns(&mut buf, nanos * 1_000_003).unwrap();
+        }
+        assert_eq!(buf.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_format_ms_input() {
+        assert_eq!(format_ms_input(0), "0ms");
+        assert_eq!(format_ms_input(1), "1ms");
+        assert_eq!(format_ms_input(999), "999ms");
+        // exact seconds
+        assert_eq!(format_ms_input(1000), "1s");
+        assert_eq!(format_ms_input(60_000), "1m");
+        assert_eq!(format_ms_input(1001), "1s1ms");
+        assert_eq!(format_ms_input(1500), "1s500ms");
+        assert_eq!(format_ms_input(90_061_500), "1d1h1m1s500ms");
+        assert_eq!(format_ms_input(6_000_000_000_u64), "69d10h40m");
+        assert_eq!(format_ms_input(u64::MAX), "213503982334d14h25m51s615ms");
+
+        for millis in (1..10_000_000_u64).step_by(997) {
+            assert_eq!(format_ms_input(millis), format_ns(millis * 1_000_000));
+        }
+    }
+
+    #[test]
+    fn test_format_u128() {
+        let wide = u128::from(u64::MAX) + 1;
+        // the generic functions only keep the lower 64 bits
+        assert_eq!(format_dhms(wide + 5), "5s");
+        assert_eq!(format_dhms_u128(wide + 5), "213503982334601d7h21s");
+        assert_eq!(format_dhms_u128(wide), "213503982334601d7h16s");
+        assert_eq!(format_wdhms_u128(wide), "30500568904943w7h16s");
+        assert_eq!(format_ns_u128(wide), "213503d23h34m33s709ms551\u{b5}s616ns");
+        assert_eq!(
+            format_dhms_u128(u128::MAX),
+            "3938453320844195178974243141571391d8h4m15s"
+        );
+        assert_eq!(
+            format_ns_u128(u128::MAX),
+            format_duration_u128_ns(u128::MAX)
+        );
+
+        assert_eq!(format_dhms_u128(0), "0s");
+        assert_eq!(format_wdhms_u128(0), "0s");
+        assert_eq!(format_ns_u128(0), "0ns");
+        for value in (0..1009).map(|i| i * (u64::MAX / 1009)) {
+            assert_eq!(format_dhms_u128(u128::from(value)), format_dhms(value));
+            assert_eq!(format_wdhms_u128(u128::from(value)), format_wdhms(value));
+            assert_eq!(format_ns_u128(u128::from(value)), format_ns(value));
+        }
+    }
+
+    #[test]
+    fn test_format_ns_checked() {
+        assert_eq!(format_ns_checked(0).as_deref(), Ok("0ns"));
+        assert_eq!(
+            format_ns_checked(3_000_129_723).as_deref(),
+            Ok("3s129\u{b5}s723ns")
+        );
+        assert_eq!(
+            format_ns_checked(u128::from(u64::MAX)),
+            Ok(format_ns(u64::MAX))
+        );
+        assert_eq!(
+            format_ns_checked(u128::from(u64::MAX) + 1),
+            Err(ConversionError)
+        );
+        assert_eq!(format_ns_checked(u128::MAX), Err(ConversionError));
+    }
+
+    /// Reference implementation splitting nanoseconds at the second boundary
+    fn format_duration_u128_ns(nanos: u128) -> String {
+        let secs = format_dhms_u128(nanos / 1_000_000_000);
+        let subsec = format_ns((nanos % 1_000_000_000) as u64);
+        match (secs.as_str(), subsec.as_str()) {
+            ("0s", _) => subsec,
+            (_, "0ns") => secs,
+            _ => secs + &subsec,
+        }
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::ZERO), "0ns");
+        assert_eq!(format_duration(Duration::from_nanos(1)), "1ns");
+        assert_eq!(format_duration(Duration::from_secs(6_000_000)), "69d10h40m");
+        assert_eq!(
+            format_duration(Duration::from_nanos(100_000_000_010_100_001)),
+            "1157d9h46m40s10ms100\u{b5}s1ns"
+        );
+        for nanos in (0..1009).map(|i| i * (u64::MAX / 1009)) {
+            assert_eq!(
+                format_duration(Duration::from_nanos(nanos)),
+                format_ns(nanos)
+            );
+        }
+
+        // larger than u64::MAX nanoseconds
+        assert_eq!(
+            format_duration(Duration::from_secs(u64::MAX)),
+            "213503982334601d7h15s"
+        );
+        assert_eq!(
+            format_duration(Duration::new(u64::MAX, 999_999_999)),
+            "213503982334601d7h15s999ms999\u{b5}s999ns"
+        );
+    }
+
+    #[test]
+    fn test_format_duration_dhms() {
+        assert_eq!(format_duration_dhms(Duration::ZERO), "0s");
+        assert_eq!(format_duration_dhms(Duration::from_millis(999)), "0s");
+        assert_eq!(format_duration_dhms(Duration::new(7259, 500)), "2h59s");
+        assert_eq!(
+            format_duration_dhms(Duration::from_secs(u64::MAX)),
+            "213503982334601d7h15s"
+        );
+    }
+
+    #[test]
+    fn test_format_duration_ref() {
+        let durations = [
+            Duration::ZERO,
+            Duration::from_nanos(1),
+            Duration::from_millis(999),
+            Duration::new(7259, 500),
+            Duration::from_secs(6_000_000),
+            Duration::new(u64::MAX, 999_999_999),
+        ];
+        for duration in &durations {
+            assert_eq!(format_duration_ref(duration), format_duration(*duration));
+            assert_eq!(
+                format_duration_dhms_ref(duration),
+                format_duration_dhms(*duration)
+            );
+        }
+        assert_eq!(
+            format_duration_ref(&Duration::new(61, 1_000_001)),
+            "1m1s1ms1ns"
+        );
+        assert_eq!(
+            format_duration_dhms_ref(&Duration::new(61, 1_000_001)),
+            "1m1s"
+        );
+    }
 }
@@ -0,0 +1,283 @@
+use crate::{dhms_parts, to_u64, write_dhms};
+use alloc::string::String;
+use core::convert::{TryFrom, TryInto};
+use core::fmt::{self, Debug, Write};
+use core::ops::BitAnd;
+
+/// Convert seconds to long compound duration (days, hours, minutes, seconds)
+///
+/// Uses the same units as [`format_dhms`](crate::format_dhms) but spelled out
+/// in words and separated by spaces.
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_long;
+///
+/// assert_eq!(format_dhms_long(6_000_000), "69 days 10 hours 40 minutes");
+/// assert_eq!(format_dhms_long(86_401), "1 day 1 second");
+///```
+#[must_use]
+pub fn format_dhms_long<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(seconds: T) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_dhms_long(&mut compound_duration, to_u64(seconds)).unwrap();
+    compound_duration
+}
+
+/// Write seconds as long compound duration (days, hours, minutes, seconds)
+///
+/// Same output as [`format_dhms_long`] but appended to an existing
+/// [`fmt::Write`].
+///
+/// # Errors
+///
+/// Returns an error only if writing to `out` fails.
+///
+/// Example:
+///```
+/// use compound_duration::write_dhms_long;
+///
+/// let mut buf = String::from("uptime: ");
+/// write_dhms_long(&mut buf, 86_401).unwrap();
+/// assert_eq!(buf, "uptime: 1 day 1 second");
+///```
+pub fn write_dhms_long<W: fmt::Write>(out: &mut W, seconds: u64) -> fmt::Result {
+    write_words(out, seconds, &FULL)
+}
+
+/// Convert seconds to long compound duration (days, hours, minutes, seconds)
+/// as an English phrase
+///
+/// The units are spelled out and pluralized like [`format_dhms_long`], joined
+/// by commas with `"and"` before the last one and no comma before the `"and"`.
+/// Two units are only joined by `"and"` and a single unit is written alone.
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_prose;
+///
+/// assert_eq!(format_dhms_prose(6_000_000), "69 days, 10 hours and 40 minutes");
+/// assert_eq!(format_dhms_prose(3601), "1 hour and 1 second");
+/// assert_eq!(format_dhms_prose(86_400), "1 day");
+///```
+#[must_use]
+pub fn format_dhms_prose<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(seconds: T) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let seconds = to_u64(seconds);
+    if seconds == 0 {
+        return String::from("0 seconds");
+    }
+
+    let parts = dhms_parts(seconds);
+    let mut words = parts
+        .iter()
+        .zip(FULL.iter())
+        .filter(|((value, _), _)| *value != 0)
+        .map(|((value, _), &(singular, plural))| {
+            (*value, if *value == 1 { singular } else { plural })
+        })
+        .peekable();
+
+    let mut compound_duration = String::new();
+    let mut first = true;
+    while let Some((value, label)) = words.next() {
+        if !first {
+            compound_duration.push_str(if words.peek().is_some() {
+                ", "
+            } else {
+                " and "
+            });
+        }
+        first = false;
+        // writing to a String never fails
+        write!(compound_duration, "{value} {label}").unwrap();
+    }
+
+    compound_duration
+}
+
+/// `(singular, plural)` labels for days, hours, minutes and seconds
+type Words = [(&'static str, &'static str); 4];
+
+const FULL: Words = [
+    ("day", "days"),
+    ("hour", "hours"),
+    ("minute", "minutes"),
+    ("second", "seconds"),
+];
+
+const MEDIUM: Words = [
+    ("day", "days"),
+    ("hr", "hr"),
+    ("min", "min"),
+    ("sec", "sec"),
+];
+
+/// Write the non-zero units of `seconds` as `<value> <label>` separated by
+/// spaces, zero is written as zero seconds
+fn write_words<W: fmt::Write>(out: &mut W, seconds: u64, words: &Words) -> fmt::Result {
+    if seconds == 0 {
+        return write!(out, "0 {}", words[3].1);
+    }
+
+    let mut first = true;
+    for (&(value, _), &(singular, plural)) in dhms_parts(seconds).iter().zip(words.iter()) {
+        if value == 0 {
+            continue;
+        }
+        if !first {
+            out.write_char(' ')?;
+        }
+        first = false;
+        let label = if value == 1 { singular } else { plural };
+        write!(out, "{value} {label}")?;
+    }
+
+    Ok(())
+}
+
+/// How the units are labeled by [`format_dhms_styled`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LabelStyle {
+    /// Single letters without spaces, `"2d3h4m"`, same as
+    /// [`format_dhms`](crate::format_dhms)
+    #[default]
+    Compact,
+    /// Abbreviated words, `"2 days 3 hr 4 min"`, only days are pluralized
+    Medium,
+    /// Full words, `"2 days 3 hours 4 minutes"`, same as [`format_dhms_long`]
+    Full,
+}
+
+/// Convert seconds to compound duration (days, hours, minutes, seconds) with
+/// the units labeled in `style`
+///
+/// Example:
+///```
+/// use compound_duration::{format_dhms_styled, LabelStyle};
+///
+/// assert_eq!(format_dhms_styled(183_840, LabelStyle::Compact), "2d3h4m");
+/// assert_eq!(format_dhms_styled(183_840, LabelStyle::Medium), "2 days 3 hr 4 min");
+/// assert_eq!(format_dhms_styled(183_840, LabelStyle::Full), "2 days 3 hours 4 minutes");
+///```
+#[must_use]
+pub fn format_dhms_styled<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    style: LabelStyle,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let seconds = to_u64(seconds);
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    match style {
+        LabelStyle::Compact => write_dhms(&mut compound_duration, seconds),
+        LabelStyle::Medium => write_words(&mut compound_duration, seconds, &MEDIUM),
+        LabelStyle::Full => write_words(&mut compound_duration, seconds, &FULL),
+    }
+    .unwrap();
+    compound_duration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        format_dhms_long, format_dhms_prose, format_dhms_styled, write_dhms_long, LabelStyle,
+    };
+    use crate::format_dhms;
+    use alloc::string::String;
+
+    #[test]
+    fn test_format_dhms_long() {
+        assert_eq!(format_dhms_long(0), "0 seconds");
+        assert_eq!(format_dhms_long(1), "1 second");
+        assert_eq!(format_dhms_long(30), "30 seconds");
+        assert_eq!(format_dhms_long(61), "1 minute 1 second");
+        assert_eq!(format_dhms_long(3600), "1 hour");
+        assert_eq!(format_dhms_long(7259), "2 hours 59 seconds");
+        assert_eq!(format_dhms_long(86400), "1 day");
+        assert_eq!(format_dhms_long(183_840), "2 days 3 hours 4 minutes");
+        assert_eq!(format_dhms_long(604_800), "7 days");
+        assert_eq!(format_dhms_long(6_000_000), "69 days 10 hours 40 minutes");
+        assert_eq!(
+            format_dhms_long(4_294_967_295_usize),
+            "49710 days 6 hours 28 minutes 15 seconds"
+        );
+    }
+
+    #[test]
+    fn test_write_dhms_long() {
+        let mut buf = String::from("up ");
+        write_dhms_long(&mut buf, 0).unwrap();
+        assert_eq!(buf, "up 0 seconds");
+
+        let mut buf = String::new();
+        write_dhms_long(&mut buf, 6_000_000).unwrap();
+        write_dhms_long(&mut buf, 1).unwrap();
+        assert_eq!(buf, "69 days 10 hours 40 minutes1 second");
+    }
+
+    #[test]
+    fn test_format_dhms_styled() {
+        use LabelStyle::{Compact, Full, Medium};
+
+        assert_eq!(format_dhms_styled(0, Compact), "0s");
+        assert_eq!(format_dhms_styled(0, Medium), "0 sec");
+        assert_eq!(format_dhms_styled(0, Full), "0 seconds");
+        assert_eq!(format_dhms_styled(1, Medium), "1 sec");
+        assert_eq!(format_dhms_styled(61, Medium), "1 min 1 sec");
+        assert_eq!(format_dhms_styled(7259, Medium), "2 hr 59 sec");
+        assert_eq!(format_dhms_styled(86_400, Medium), "1 day");
+        assert_eq!(format_dhms_styled(183_840, Medium), "2 days 3 hr 4 min");
+        assert_eq!(
+            format_dhms_styled(6_000_000, Medium),
+            "69 days 10 hr 40 min"
+        );
+        assert_eq!(LabelStyle::default(), Compact);
+
+        for seconds in (0..1_000_000).step_by(997) {
+            assert_eq!(format_dhms_styled(seconds, Compact), format_dhms(seconds));
+            assert_eq!(format_dhms_styled(seconds, Full), format_dhms_long(seconds));
+        }
+    }
+
+    #[test]
+    fn test_format_dhms_prose() {
+        assert_eq!(format_dhms_prose(0), "0 seconds");
+        assert_eq!(format_dhms_prose(1), "1 second");
+        assert_eq!(format_dhms_prose(30), "30 seconds");
+        assert_eq!(format_dhms_prose(86_400), "1 day");
+        assert_eq!(format_dhms_prose(61), "1 minute and 1 second");
+        assert_eq!(format_dhms_prose(3601), "1 hour and 1 second");
+        assert_eq!(format_dhms_prose(7259), "2 hours and 59 seconds");
+        assert_eq!(format_dhms_prose(183_840), "2 days, 3 hours and 4 minutes");
+        assert_eq!(
+            format_dhms_prose(6_000_000),
+            "69 days, 10 hours and 40 minutes"
+        );
+        assert_eq!(
+            format_dhms_prose(90_061),
+            "1 day, 1 hour, 1 minute and 1 second"
+        );
+        assert_eq!(
+            format_dhms_prose(4_294_967_295_usize),
+            "49710 days, 6 hours, 28 minutes and 15 seconds"
+        );
+
+        for seconds in (0..1_000_000).step_by(997) {
+            let prose = format_dhms_prose(seconds)
+                .replace(" and", "")
+                .replace(',', "");
+            assert_eq!(prose, format_dhms_long(seconds));
+        }
+    }
+}
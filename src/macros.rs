@@ -0,0 +1,93 @@
+/// Total seconds of `$value $unit` pairs checked at compile time
+///
+/// Units are `w`, `d`, `h`, `m`, `s` and must appear in that order, each one at
+/// most once, separated from their value by a space. The expansion is a plain
+/// `u64` so it can be used in `const` position, unknown units, units out of
+/// order and totals that overflow a `u64` fail to compile.
+///
+/// Example:
+///```
+/// use compound_duration::compound_duration;
+///
+/// const TIMEOUT: u64 = compound_duration!(1 d 2 h 30 m);
+/// assert_eq!(TIMEOUT, 95_400);
+/// assert_eq!(compound_duration!(90 m), 5400);
+///```
+///
+/// Units out of order don't compile:
+///```compile_fail
+/// use compound_duration::compound_duration;
+///
+/// const TIMEOUT: u64 = compound_duration!(30 m 2 h);
+///```
+///
+/// Neither do unknown units:
+///```compile_fail
+/// use compound_duration::compound_duration;
+///
+/// const TIMEOUT: u64 = compound_duration!(1 y);
+///```
+#[macro_export]
+macro_rules! compound_duration {
+    (@unit w) => { $crate::SECONDS_PER_WEEK as u64 };
+    (@unit d) => { $crate::SECONDS_PER_DAY as u64 };
+    (@unit h) => { $crate::SECONDS_PER_HOUR as u64 };
+    (@unit m) => { $crate::SECONDS_PER_MINUTE as u64 };
+    (@unit s) => { $crate::SECOND as u64 };
+    (@unit $unit:ident) => {
+        compile_error!(concat!("unknown unit `", stringify!($unit), "`, expected w, d, h, m or s"))
+    };
+    ($($value:literal $unit:ident)+) => {{
+        const SECONDS: u64 =
+            $crate::__compound_duration(&[$(($value, $crate::compound_duration!(@unit $unit))),+]);
+        SECONDS
+    }};
+}
+
+/// Add up the `(value, unit)` pairs of [`compound_duration!`], panicking if
+/// the units are not strictly decreasing or the total overflows
+#[doc(hidden)]
+#[must_use]
+pub const fn __compound_duration(parts: &[(u64, u64)]) -> u64 {
+    let mut total: u64 = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let (value, unit) = parts[i];
+        if i > 0 && unit >= parts[i - 1].1 {
+            panic!("compound_duration! units must be in w, d, h, m, s order without repeats");
+        }
+        total = match value.checked_mul(unit) {
+            Some(seconds) => match total.checked_add(seconds) {
+                Some(total) => total,
+                None => panic!("compound_duration! overflows a u64"),
+            },
+            None => panic!("compound_duration! overflows a u64"),
+        };
+        i += 1;
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_wdhms;
+
+    #[test]
+    fn test_compound_duration() {
+        const TIMEOUT: u64 = compound_duration!(1 d 2 h 30 m);
+        assert_eq!(TIMEOUT, 95_400);
+
+        assert_eq!(compound_duration!(0 s), 0);
+        assert_eq!(compound_duration!(30 s), 30);
+        assert_eq!(compound_duration!(1 m 1 s), 61);
+        assert_eq!(compound_duration!(90 m), 5400);
+        assert_eq!(compound_duration!(1 w), 604_800);
+        assert_eq!(compound_duration!(9 w 6 d 10 h 40 m), 6_000_000);
+        assert_eq!(compound_duration!(69 d 10 h 40 m), 6_000_000);
+        assert_eq!(
+            compound_duration!(7101 w 3 d 6 h 28 m 15 s),
+            parse_wdhms("7101w3d6h28m15s").unwrap()
+        );
+        assert_eq!(compound_duration!(18_446_744_073_709_551_615 s), u64::MAX);
+    }
+}
@@ -0,0 +1,260 @@
+use crate::{dhms_parts, split_wdhms, to_u64, Unit};
+use alloc::string::String;
+use core::convert::{TryFrom, TryInto};
+use core::fmt::{Debug, Write};
+use core::ops::BitAnd;
+
+/// Width of the days field in [`format_dhms_padded`]
+const DAYS_WIDTH: usize = 3;
+
+/// Convert seconds to a fixed width compound duration (days, hours, minutes,
+/// seconds) for column alignment
+///
+/// Every unit is always shown, zero or not. Days are zero padded to three
+/// digits and hours, minutes and seconds to two, so any duration under 1000
+/// days is exactly 13 characters wide. Longer durations keep all their day
+/// digits and grow to the left.
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_padded;
+///
+/// assert_eq!(format_dhms_padded(61), "000d00h01m01s");
+/// assert_eq!(format_dhms_padded(6_000_000), "069d10h40m00s");
+///```
+#[must_use]
+pub fn format_dhms_padded<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(seconds: T) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let [(days, d), (hours, h), (minutes, m), (secs, s)] = dhms_parts(to_u64(seconds));
+
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write!(
+        compound_duration,
+        "{days:0DAYS_WIDTH$}{d}{hours:02}{h}{minutes:02}{m}{secs:02}{s}"
+    )
+    .unwrap();
+    compound_duration
+}
+
+/// Convert seconds to compound duration (week, days, hours, minutes, seconds)
+/// always showing every unit, zero or not
+///
+/// The output always has the five fields in the same order, zero is
+/// `"0w0d0h0m0s"` instead of `"0s"`. Values are not padded, see
+/// [`format_dhms_padded`] for a fixed width.
+///
+/// Example:
+///```
+/// use compound_duration::format_wdhms_full;
+///
+/// assert_eq!(format_wdhms_full(61), "0w0d0h1m1s");
+/// assert_eq!(format_wdhms_full(6_000_000), "9w6d10h40m0s");
+///```
+#[must_use]
+pub fn format_wdhms_full<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(seconds: T) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let (weeks, days, hours, minutes, secs) = split_wdhms(to_u64(seconds));
+
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write!(
+        compound_duration,
+        "{weeks}w{days}d{hours}h{minutes}m{secs}s"
+    )
+    .unwrap();
+    compound_duration
+}
+
+/// Convert seconds to colon separated fields (weeks, days, hours, minutes,
+/// seconds) for systems that split on `:`
+///
+/// All five fields are always present, `"w:d:h:m:ss"`. Weeks, days, hours and
+/// minutes are written without padding, weeks are unbounded, days take one
+/// digit and hours and minutes one or two. Seconds are always zero padded to
+/// two digits, matching the `9:6:10:40:00` layout.
+///
+/// Example:
+///```
+/// use compound_duration::format_wdhms_colon;
+///
+/// assert_eq!(format_wdhms_colon(61), "0:0:0:1:01");
+/// assert_eq!(format_wdhms_colon(6_000_000), "9:6:10:40:00");
+///```
+#[must_use]
+pub fn format_wdhms_colon<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(seconds: T) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let (weeks, days, hours, minutes, secs) = split_wdhms(to_u64(seconds));
+
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write!(
+        compound_duration,
+        "{weeks}:{days}:{hours}:{minutes}:{secs:02}"
+    )
+    .unwrap();
+    compound_duration
+}
+
+/// Convert seconds to compound duration (days, hours, minutes, seconds)
+/// showing every unit down to `floor`, zero or not
+///
+/// Units are shown from the largest non-zero one, or `floor` if that is
+/// larger, down to `floor` even when they are zero, `3600` with a
+/// [`Unit::Minute`] floor is `"1h0m"`. Units below `floor` are only shown when
+/// they are not zero, like in [`format_dhms`](crate::format_dhms). Unlike
+/// [`format_wdhms_full`] the leading units are not padded and the floor is
+/// chosen by the caller. Weeks are not a dhms unit, so a [`Unit::Week`] floor
+/// is treated as [`Unit::Day`], and floors under a second as [`Unit::Second`].
+///
+/// Example:
+///```
+/// use compound_duration::{format_dhms_min_units, Unit};
+///
+/// assert_eq!(format_dhms_min_units(3600, Unit::Minute), "1h0m");
+/// assert_eq!(format_dhms_min_units(3600, Unit::Second), "1h0m0s");
+/// assert_eq!(format_dhms_min_units(30, Unit::Minute), "0m30s");
+///```
+#[must_use]
+pub fn format_dhms_min_units<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    floor: Unit,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let floor = floor.clamp(Unit::Second, Unit::Day);
+    // index of the floor in the dhms parts, days first
+    let floor = match floor {
+        Unit::Day => 0,
+        Unit::Hour => 1,
+        Unit::Minute => 2,
+        _ => 3,
+    };
+    let parts = dhms_parts(to_u64(seconds));
+    let first = parts
+        .iter()
+        .position(|(value, _)| *value != 0)
+        .map_or(floor, |first| first.min(floor));
+
+    let mut compound_duration = String::new();
+    for (index, (value, suffix)) in parts.iter().enumerate().skip(first) {
+        if index <= floor || *value != 0 {
+            // writing to a String never fails
+            write!(compound_duration, "{value}{suffix}").unwrap();
+        }
+    }
+    compound_duration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_dhms_min_units, format_dhms_padded, format_wdhms_colon, format_wdhms_full};
+    use crate::{parse_dhms, split_wdhms, Unit};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_format_dhms_padded() {
+        assert_eq!(format_dhms_padded(0), "000d00h00m00s");
+        assert_eq!(format_dhms_padded(1), "000d00h00m01s");
+        assert_eq!(format_dhms_padded(61), "000d00h01m01s");
+        assert_eq!(format_dhms_padded(7259), "000d02h00m59s");
+        assert_eq!(format_dhms_padded(86_400), "001d00h00m00s");
+        assert_eq!(format_dhms_padded(6_000_000), "069d10h40m00s");
+        assert_eq!(format_dhms_padded(86_399_999), "999d23h59m59s");
+        assert_eq!(format_dhms_padded(86_400_000), "1000d00h00m00s");
+        assert_eq!(format_dhms_padded(4_294_967_295_u32), "49710d06h28m15s");
+
+        // short and long durations line up
+        assert_eq!(
+            format_dhms_padded(61).len(),
+            format_dhms_padded(6_000_000).len()
+        );
+    }
+
+    #[test]
+    fn test_format_wdhms_full() {
+        assert_eq!(format_wdhms_full(0), "0w0d0h0m0s");
+        assert_eq!(format_wdhms_full(1), "0w0d0h0m1s");
+        assert_eq!(format_wdhms_full(61), "0w0d0h1m1s");
+        assert_eq!(format_wdhms_full(3600), "0w0d1h0m0s");
+        assert_eq!(format_wdhms_full(604_800), "1w0d0h0m0s");
+        assert_eq!(format_wdhms_full(6_000_000), "9w6d10h40m0s");
+        assert_eq!(format_wdhms_full(4_294_967_295_u32), "7101w3d6h28m15s");
+
+        // always five fields
+        for &seconds in &[0_u64, 59, 86_400, 6_000_000, u64::MAX] {
+            let full = format_wdhms_full(seconds);
+            assert_eq!(full.matches(char::is_alphabetic).count(), 5);
+        }
+    }
+
+    #[test]
+    fn test_format_wdhms_colon() {
+        assert_eq!(format_wdhms_colon(0), "0:0:0:0:00");
+        assert_eq!(format_wdhms_colon(1), "0:0:0:0:01");
+        assert_eq!(format_wdhms_colon(61), "0:0:0:1:01");
+        assert_eq!(format_wdhms_colon(3600), "0:0:1:0:00");
+        assert_eq!(format_wdhms_colon(86_399), "0:0:23:59:59");
+        assert_eq!(format_wdhms_colon(604_800), "1:0:0:0:00");
+        assert_eq!(format_wdhms_colon(6_000_000), "9:6:10:40:00");
+        assert_eq!(format_wdhms_colon(4_294_967_295_u32), "7101:3:6:28:15");
+        assert_eq!(format_wdhms_colon(u64::MAX), "30500568904943:0:7:0:15");
+
+        // always five fields that read back as the same split
+        for seconds in (0..1000).map(|i| i * (u64::MAX / 1000)).chain(0..100_000) {
+            let fields: Vec<u64> = format_wdhms_colon(seconds)
+                .split(':')
+                .map(|field| field.parse().unwrap())
+                .collect();
+            let (w, d, h, m, s) = split_wdhms(seconds);
+            assert_eq!(fields, [w, d, h, m, s]);
+        }
+    }
+
+    #[test]
+    fn test_format_dhms_min_units() {
+        assert_eq!(format_dhms_min_units(3600, Unit::Minute), "1h0m");
+        assert_eq!(format_dhms_min_units(3600, Unit::Second), "1h0m0s");
+        assert_eq!(format_dhms_min_units(3600, Unit::Hour), "1h");
+        assert_eq!(format_dhms_min_units(3601, Unit::Minute), "1h0m1s");
+        assert_eq!(format_dhms_min_units(86_400, Unit::Second), "1d0h0m0s");
+        assert_eq!(format_dhms_min_units(86_401, Unit::Hour), "1d0h1s");
+        assert_eq!(
+            format_dhms_min_units(6_000_000, Unit::Second),
+            "69d10h40m0s"
+        );
+
+        // the floor is shown even if it's larger than the duration
+        assert_eq!(format_dhms_min_units(0, Unit::Minute), "0m");
+        assert_eq!(format_dhms_min_units(0, Unit::Second), "0s");
+        assert_eq!(format_dhms_min_units(30, Unit::Minute), "0m30s");
+        assert_eq!(format_dhms_min_units(61, Unit::Hour), "0h1m1s");
+        assert_eq!(format_dhms_min_units(61, Unit::Day), "0d1m1s");
+
+        // weeks are days, under a second is a second
+        assert_eq!(format_dhms_min_units(61, Unit::Week), "0d1m1s");
+        assert_eq!(format_dhms_min_units(3600, Unit::Nano), "1h0m0s");
+        assert_eq!(format_dhms_min_units(0, Unit::Milli), "0s");
+
+        for seconds in (0..1_000_000).step_by(997) {
+            let padded = format_dhms_min_units(seconds, Unit::Second);
+            assert_eq!(parse_dhms(&padded), Ok(seconds));
+            assert!(padded.ends_with('s'));
+            assert_eq!(
+                format_dhms_min_units(seconds, Unit::Nano),
+                format_dhms_min_units(seconds, Unit::Second)
+            );
+        }
+    }
+}
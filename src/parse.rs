@@ -0,0 +1,1063 @@
+use crate::{
+    ParseError, NANOS_PER_MS, NANOS_PER_SECOND, NANOS_PER_US, SECOND, SECONDS_PER_DAY,
+    SECONDS_PER_HOUR, SECONDS_PER_MINUTE, SECONDS_PER_WEEK,
+};
+use core::time::Duration;
+
+/// Units accepted by a parser, largest first, as `(suffixes, multiplier)`
+pub(crate) type UnitTable = [(&'static [&'static str], u64)];
+
+const DHMS: &UnitTable = &[
+    (&["d"], SECONDS_PER_DAY as u64),
+    (&["h"], SECONDS_PER_HOUR as u64),
+    (&["m"], SECONDS_PER_MINUTE as u64),
+    (&["s"], SECOND as u64),
+];
+
+const WDHMS: &UnitTable = &[
+    (&["w"], SECONDS_PER_WEEK as u64),
+    (&["d"], SECONDS_PER_DAY as u64),
+    (&["h"], SECONDS_PER_HOUR as u64),
+    (&["m"], SECONDS_PER_MINUTE as u64),
+    (&["s"], SECOND as u64),
+];
+
+const WEEK_ALIASES: &[&str] = &["w", "week", "weeks"];
+const DAY_ALIASES: &[&str] = &["d", "day", "days"];
+const HOUR_ALIASES: &[&str] = &["h", "hr", "hrs", "hour", "hours"];
+const MINUTE_ALIASES: &[&str] = &["m", "min", "mins", "minute", "minutes"];
+const SECOND_ALIASES: &[&str] = &["s", "sec", "secs", "second", "seconds"];
+
+const LENIENT_DHMS: &UnitTable = &[
+    (DAY_ALIASES, SECONDS_PER_DAY as u64),
+    (HOUR_ALIASES, SECONDS_PER_HOUR as u64),
+    (MINUTE_ALIASES, SECONDS_PER_MINUTE as u64),
+    (SECOND_ALIASES, SECOND as u64),
+];
+
+const LENIENT_WDHMS: &UnitTable = &[
+    (WEEK_ALIASES, SECONDS_PER_WEEK as u64),
+    (DAY_ALIASES, SECONDS_PER_DAY as u64),
+    (HOUR_ALIASES, SECONDS_PER_HOUR as u64),
+    (MINUTE_ALIASES, SECONDS_PER_MINUTE as u64),
+    (SECOND_ALIASES, SECOND as u64),
+];
+
+/// Index of the seconds in [`NS_UNITS`], the only unit with a decimal part
+const NS_SECONDS: usize = 3;
+
+const NS_UNITS: &UnitTable = &[
+    (&["d"], SECONDS_PER_DAY as u64 * NANOS_PER_SECOND as u64),
+    (&["h"], SECONDS_PER_HOUR as u64 * NANOS_PER_SECOND as u64),
+    (&["m"], SECONDS_PER_MINUTE as u64 * NANOS_PER_SECOND as u64),
+    (&["s"], SECOND as u64 * NANOS_PER_SECOND as u64),
+    (&["ms"], NANOS_PER_MS as u64),
+    (&["\u{b5}s", "us"], NANOS_PER_US as u64),
+    (&["ns"], 1),
+];
+
+/// Most digits accepted after the decimal point
+const MAX_FRACTION_DIGITS: usize = 9;
+
+/// A `<number>[.<fraction>]<unit>` segment of a compound duration string
+struct Segment<'a> {
+    value: &'a str,
+    /// Digits after the decimal point and the offset of the point
+    fraction: Option<(&'a str, usize)>,
+    unit: &'a str,
+    unit_start: usize,
+}
+
+/// Splits a compound duration string into its segments
+struct Segments<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Segments<'a> {
+    const fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = Result<Segment<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.input[self.pos..];
+        if rest.is_empty() {
+            return None;
+        }
+        let start = self.pos;
+
+        // number
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if digits == 0 {
+            self.pos = self.input.len();
+            return Some(Err(ParseError::MissingNumber(start)));
+        }
+
+        // optional fraction, a point followed by digits
+        let mut fraction = None;
+        let mut number_len = digits;
+        if let Some(after_point) = rest[digits..].strip_prefix('.') {
+            let fraction_len = after_point
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(after_point.len());
+            if fraction_len != 0 {
+                fraction = Some((&after_point[..fraction_len], start + digits));
+                number_len += 1 + fraction_len;
+            }
+        }
+
+        // unit, everything up to the next digit
+        let unit_start = start + number_len;
+        let tail = &rest[number_len..];
+        let unit_len = tail
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(tail.len());
+        if unit_len == 0 {
+            self.pos = self.input.len();
+            return Some(Err(ParseError::MissingUnit(unit_start)));
+        }
+
+        self.pos = unit_start + unit_len;
+        Some(Ok(Segment {
+            value: &rest[..digits],
+            fraction,
+            unit: &tail[..unit_len],
+            unit_start,
+        }))
+    }
+}
+
+/// Parse `input` using `units`, segments must follow the order of the table
+pub(crate) fn parse_ordered(input: &str, units: &UnitTable) -> Result<u64, ParseError> {
+    parse_ordered_decimal(input, units, None, false)
+}
+
+/// Parse `input` using `units` like [`parse_ordered`], the unit at index
+/// `decimal` of the table also accepts a fraction of up to 9 digits
+///
+/// A unit repeated right after itself is reported as
+/// [`ParseError::DuplicateUnit`] when `strict` is set, otherwise as
+/// [`ParseError::OutOfOrder`].
+fn parse_ordered_decimal(
+    input: &str,
+    units: &UnitTable,
+    decimal: Option<usize>,
+    strict: bool,
+) -> Result<u64, ParseError> {
+    if input.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let mut total: u64 = 0;
+    // index of the largest unit still allowed
+    let mut next = 0;
+    for segment in Segments::new(input) {
+        let segment = segment?;
+        let index = units
+            .iter()
+            .position(|(suffixes, _)| suffixes.contains(&segment.unit))
+            .ok_or(ParseError::UnknownUnit(segment.unit_start))?;
+        if strict && index + 1 == next {
+            return Err(ParseError::DuplicateUnit(segment.unit_start));
+        }
+        if index < next {
+            return Err(ParseError::OutOfOrder(segment.unit_start));
+        }
+        next = index + 1;
+
+        // the value only contains ASCII digits, so it can only fail on overflow
+        let value: u64 = segment.value.parse().map_err(|_| ParseError::Overflow)?;
+        total = value
+            .checked_mul(units[index].1)
+            .and_then(|v| total.checked_add(v))
+            .ok_or(ParseError::Overflow)?;
+
+        if let Some((digits, point)) = segment.fraction {
+            if decimal != Some(index) {
+                return Err(ParseError::Fractional(point));
+            }
+            if digits.len() > MAX_FRACTION_DIGITS {
+                return Err(ParseError::ExcessPrecision(point + 1 + MAX_FRACTION_DIGITS));
+            }
+            // at most 9 digits, it always fits and the scale divides exactly
+            let fraction: u64 = digits.parse().map_err(|_| ParseError::Overflow)?;
+            let scale = 10_u64.pow(digits.len() as u32);
+            total = total
+                .checked_add(fraction * (units[index].1 / scale))
+                .ok_or(ParseError::Overflow)?;
+        }
+    }
+
+    Ok(total)
+}
+
+/// Whether `c` is skipped by [`parse_lenient`]
+const fn is_lenient_separator(c: char) -> bool {
+    c.is_ascii_whitespace() || c == ','
+}
+
+/// Parse `input` using `units` in any order, ignoring ASCII whitespace, commas
+/// and the case of the units
+///
+/// A unit is every character up to the next digit or separator, so it has to
+/// match a whole suffix, the longest one always wins and `"1ms"` is never
+/// read as minutes followed by a stray `s`.
+pub(crate) fn parse_lenient(input: &str, units: &UnitTable) -> Result<u64, ParseError> {
+    let skip = |pos: usize| {
+        input[pos..]
+            .find(|c: char| !is_lenient_separator(c))
+            .map_or(input.len(), |n| pos + n)
+    };
+
+    let mut total: u64 = 0;
+    let mut pos = skip(0);
+    if pos == input.len() {
+        return Err(ParseError::Empty);
+    }
+    while pos < input.len() {
+        let digits = input[pos..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len() - pos);
+        if digits == 0 {
+            return Err(ParseError::MissingNumber(pos));
+        }
+        let value = &input[pos..pos + digits];
+
+        let unit_start = skip(pos + digits);
+        let unit_len = input[unit_start..]
+            .find(|c: char| c.is_ascii_digit() || is_lenient_separator(c))
+            .unwrap_or(input.len() - unit_start);
+        if unit_len == 0 {
+            return Err(ParseError::MissingUnit(unit_start));
+        }
+        let unit = &input[unit_start..unit_start + unit_len];
+
+        let (_, size) = units
+            .iter()
+            .find(|(suffixes, _)| suffixes.iter().any(|s| s.eq_ignore_ascii_case(unit)))
+            .ok_or(ParseError::UnknownUnit(unit_start))?;
+
+        // the value only contains ASCII digits, so it can only fail on overflow
+        let value: u64 = value.parse().map_err(|_| ParseError::Overflow)?;
+        total = value
+            .checked_mul(*size)
+            .and_then(|v| total.checked_add(v))
+            .ok_or(ParseError::Overflow)?;
+
+        pos = skip(unit_start + unit_len);
+    }
+
+    Ok(total)
+}
+
+/// Parse a compound duration (days, hours, minutes, seconds) into seconds
+///
+/// This is the inverse of [`format_dhms`](crate::format_dhms), any subset of
+/// the `d`, `h`, `m`, `s` units is accepted as long as they appear in that
+/// order.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the input is empty, contains an unknown unit,
+/// has units out of order or the total overflows a `u64`.
+///
+/// Example:
+///```
+/// use compound_duration::parse_dhms;
+///
+/// assert_eq!(parse_dhms("69d10h40m"), Ok(6_000_000));
+///```
+pub fn parse_dhms(input: &str) -> Result<u64, ParseError> {
+    parse_ordered(input, DHMS)
+}
+
+/// Parse a machine generated compound duration (days, hours, minutes,
+/// seconds) into seconds
+///
+/// Accepts the same input as [`parse_dhms`] but tells apart the two ways the
+/// order can be broken, so corrupted data can be reported precisely: a unit
+/// after a smaller one, `"2h1d"`, is [`ParseError::OutOfOrder`] and a unit
+/// repeated, `"1d1d"`, is [`ParseError::DuplicateUnit`]. Both are rejected
+/// where [`parse_dhms_lenient`] would add them up.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the input is empty, contains an unknown unit,
+/// has units out of order or repeated or the total overflows a `u64`.
+///
+/// Example:
+///```
+/// use compound_duration::{parse_dhms_strict, ParseError};
+///
+/// assert_eq!(parse_dhms_strict("1d2h"), Ok(93_600));
+/// assert_eq!(parse_dhms_strict("2h1d"), Err(ParseError::OutOfOrder(3)));
+/// assert_eq!(parse_dhms_strict("1d1d"), Err(ParseError::DuplicateUnit(3)));
+///```
+pub fn parse_dhms_strict(input: &str) -> Result<u64, ParseError> {
+    parse_ordered_decimal(input, DHMS, None, true)
+}
+
+/// Parse the compound duration (days, hours, minutes, seconds) at the start of
+/// `input`, returning the seconds and the number of bytes consumed
+///
+/// Meant for durations embedded in larger strings, parsing stops at the first
+/// character that can't start a new `<number><unit>` segment and the rest of
+/// the input is left to the caller. A unit is the run of letters after a
+/// number, it must be one of `d`, `h`, `m`, `s` and the units must be in order
+/// like in [`parse_dhms`], so `"1d2hours"` is an unknown unit rather than
+/// `1d2h` followed by `ours`.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the input is empty or doesn't start with a
+/// number, [`ParseError::MissingNumber`] at offset `0` when nothing can be
+/// consumed. Once a number is read it must be followed by a known unit in
+/// order and the total must fit in a `u64`.
+///
+/// Example:
+///```
+/// use compound_duration::{parse_dhms_prefix, ParseError};
+///
+/// assert_eq!(parse_dhms_prefix("1d2h remaining"), Ok((93_600, 4)));
+/// assert_eq!(parse_dhms_prefix("30s"), Ok((30, 3)));
+/// assert_eq!(parse_dhms_prefix("took 30s"), Err(ParseError::MissingNumber(0)));
+///```
+pub fn parse_dhms_prefix(input: &str) -> Result<(u64, usize), ParseError> {
+    if input.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let mut total: u64 = 0;
+    let mut pos = 0;
+    // index of the largest unit still allowed
+    let mut next = 0;
+    loop {
+        let rest = &input[pos..];
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if digits == 0 {
+            if pos == 0 {
+                return Err(ParseError::MissingNumber(0));
+            }
+            break;
+        }
+
+        let unit_start = pos + digits;
+        let tail = &rest[digits..];
+        let unit_len = tail
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(tail.len());
+        if unit_len == 0 {
+            return Err(ParseError::MissingUnit(unit_start));
+        }
+        let unit = &tail[..unit_len];
+        let index = DHMS
+            .iter()
+            .position(|(suffixes, _)| suffixes.contains(&unit))
+            .ok_or(ParseError::UnknownUnit(unit_start))?;
+        if index < next {
+            return Err(ParseError::OutOfOrder(unit_start));
+        }
+        next = index + 1;
+
+        // the value only contains ASCII digits, so it can only fail on overflow
+        let value: u64 = rest[..digits].parse().map_err(|_| ParseError::Overflow)?;
+        total = value
+            .checked_mul(DHMS[index].1)
+            .and_then(|v| total.checked_add(v))
+            .ok_or(ParseError::Overflow)?;
+
+        pos = unit_start + unit_len;
+    }
+
+    Ok((total, pos))
+}
+
+/// Parse a hand typed compound duration (days, hours, minutes, seconds) into
+/// seconds
+///
+/// A forgiving version of [`parse_dhms`] for interactive input:
+///
+/// * ASCII whitespace (space, tab, newline, carriage return, form feed) and
+///   commas are skipped anywhere between numbers and units, `"1d 2h"`,
+///   `"1d,2h"` and `"1 d, 2 h"` are all accepted. They can't split a number
+///   or a unit, `"1 2h"` is missing the unit of `1`.
+/// * Units are case insensitive and can be spelled out, `"1D2H"`,
+///   `"1 Day 2 Hrs"` and `"1day 2hours"` are all the same as `"1d2h"`. The
+///   accepted spellings are `d`, `day`, `days`, `h`, `hr`, `hrs`, `hour`,
+///   `hours`, `m`, `min`, `mins`, `minute`, `minutes`, `s`, `sec`, `secs`,
+///   `second` and `seconds`.
+/// * Units can appear in any order and repeated units are added up.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the input is empty or only separators, a
+/// number or a unit is missing, a unit is unknown or the total overflows a
+/// `u64`.
+///
+/// Example:
+///```
+/// use compound_duration::parse_dhms_lenient;
+///
+/// assert_eq!(parse_dhms_lenient("1D 2H"), Ok(93_600));
+/// assert_eq!(parse_dhms_lenient("1d, 2h, 30m"), Ok(95_400));
+/// assert_eq!(parse_dhms_lenient("1Day 2Hrs 30Mins"), Ok(95_400));
+///```
+pub fn parse_dhms_lenient(input: &str) -> Result<u64, ParseError> {
+    parse_lenient(input, LENIENT_DHMS)
+}
+
+/// Parse a hand typed compound duration (weeks, days, hours, minutes,
+/// seconds) into seconds
+///
+/// Same rules as [`parse_dhms_lenient`] with weeks also accepted as `w`,
+/// `week` or `weeks`.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the input is empty or only separators, a
+/// number or a unit is missing, a unit is unknown or the total overflows a
+/// `u64`.
+///
+/// Example:
+///```
+/// use compound_duration::parse_wdhms_lenient;
+///
+/// assert_eq!(parse_wdhms_lenient("2 weeks, 1 day"), Ok(1_296_000));
+/// assert_eq!(parse_wdhms_lenient("1W 1h"), Ok(608_400));
+///```
+pub fn parse_wdhms_lenient(input: &str) -> Result<u64, ParseError> {
+    parse_lenient(input, LENIENT_WDHMS)
+}
+
+/// Parse a compound duration (week, days, hours, minutes, seconds) into seconds
+///
+/// This is the inverse of [`format_wdhms`](crate::format_wdhms), any subset of
+/// the `w`, `d`, `h`, `m`, `s` units is accepted as long as they appear in that
+/// order.
+///
+/// Values are not required to be canonical, every segment is added to the
+/// total as is, so `"1w8d"` is 15 days and `"90m"` is 5400 seconds even though
+/// `format_wdhms` would never produce them.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the input is empty, contains an unknown unit,
+/// has units out of order or the total overflows a `u64`.
+///
+/// Example:
+///```
+/// use compound_duration::parse_wdhms;
+///
+/// assert_eq!(parse_wdhms("9w6d10h40m"), Ok(6_000_000));
+///```
+pub fn parse_wdhms(input: &str) -> Result<u64, ParseError> {
+    parse_ordered(input, WDHMS)
+}
+
+/// Parse a compound duration (days, hours, minutes, seconds, ms, µs, ns) into
+/// nanoseconds
+///
+/// This is the inverse of [`format_ns`](crate::format_ns), units must appear in
+/// that order. The whole suffix is read before looking it up, so `m` and `ms`
+/// or `s` and `µs` are never confused. Microseconds can be written either with
+/// `µ` (U+00B5) or with an ASCII `u`.
+///
+/// Seconds can also have a decimal part of up to 9 digits, the output of
+/// [`format_ns_decimal`](crate::format_ns_decimal), `"1.5s"` is 1.5 seconds.
+/// They can still be followed by smaller units, which are added to the total.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the input is empty, contains an unknown unit,
+/// has units out of order, a fraction on a unit other than seconds, more than
+/// 9 decimals or the total overflows a `u64`.
+///
+/// Example:
+///```
+/// use compound_duration::parse_ns;
+///
+/// assert_eq!(parse_ns("3s129\u{b5}s723ns"), Ok(3_000_129_723));
+/// assert_eq!(parse_ns("3s129us723ns"), Ok(3_000_129_723));
+/// assert_eq!(parse_ns("1.5s"), Ok(1_500_000_000));
+///```
+pub fn parse_ns(input: &str) -> Result<u64, ParseError> {
+    parse_ordered_decimal(input, NS_UNITS, Some(NS_SECONDS), false)
+}
+
+/// Parse a compound duration (days, hours, minutes, seconds, ms, µs, ns) into a
+/// [`Duration`]
+///
+/// This is the inverse of [`format_duration`](crate::format_duration), the
+/// units are the same as in [`parse_ns`], so `"1500ms"` in a config file
+/// becomes a real timeout.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] for the same reasons as [`parse_ns`], including
+/// [`ParseError::Overflow`] when the total doesn't fit in `u64` nanoseconds
+/// (about 584 years).
+///
+/// Example:
+///```
+/// use compound_duration::parse_to_std_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_to_std_duration("1500ms"), Ok(Duration::from_millis(1500)));
+/// assert_eq!(parse_to_std_duration("1m1s"), Ok(Duration::from_secs(61)));
+///```
+pub fn parse_to_std_duration(input: &str) -> Result<Duration, ParseError> {
+    let nanos = parse_ns(input)?;
+    Ok(Duration::new(
+        nanos / NANOS_PER_SECOND as u64,
+        (nanos % NANOS_PER_SECOND as u64) as u32,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        parse_dhms, parse_dhms_lenient, parse_dhms_prefix, parse_dhms_strict, parse_ns,
+        parse_to_std_duration, parse_wdhms, parse_wdhms_lenient,
+    };
+    use crate::{
+        format_dhms, format_duration, format_ns, format_ns_decimal, format_ns_decimal_trimmed,
+        format_wdhms, ParseError,
+    };
+    use alloc::string::ToString;
+    use core::time::Duration;
+
+    #[test]
+    fn test_parse_dhms() {
+        assert_eq!(parse_dhms("0s"), Ok(0));
+        assert_eq!(parse_dhms("30s"), Ok(30));
+        assert_eq!(parse_dhms("1m1s"), Ok(61));
+        assert_eq!(parse_dhms("1h"), Ok(3600));
+        assert_eq!(parse_dhms("1d1s"), Ok(86401));
+        assert_eq!(parse_dhms("2h59s"), Ok(7259));
+        assert_eq!(parse_dhms("69d10h40m"), Ok(6_000_000));
+        assert_eq!(parse_dhms("49710d6h28m15s"), Ok(4_294_967_295));
+        assert_eq!(parse_dhms("90m"), Ok(5400));
+        assert_eq!(parse_dhms(&format_dhms(123_456_789)), Ok(123_456_789));
+
+        assert_eq!(parse_dhms(""), Err(ParseError::Empty));
+        assert_eq!(parse_dhms("d"), Err(ParseError::MissingNumber(0)));
+        assert_eq!(parse_dhms("1dh"), Err(ParseError::UnknownUnit(1)));
+        assert_eq!(parse_dhms("10"), Err(ParseError::MissingUnit(2)));
+        assert_eq!(parse_dhms("1w"), Err(ParseError::UnknownUnit(1)));
+        assert_eq!(parse_dhms("10m1h"), Err(ParseError::OutOfOrder(4)));
+        assert_eq!(parse_dhms("1d1d"), Err(ParseError::OutOfOrder(3)));
+        assert_eq!(parse_dhms(" 1d"), Err(ParseError::MissingNumber(0)));
+        assert_eq!(
+            parse_dhms("18446744073709551616s"),
+            Err(ParseError::Overflow)
+        );
+        assert_eq!(parse_dhms("213503982334602d"), Err(ParseError::Overflow));
+    }
+
+    #[test]
+    fn test_parse_dhms_strict() {
+        assert_eq!(parse_dhms_strict("0s"), Ok(0));
+        assert_eq!(parse_dhms_strict("1d2h"), Ok(93_600));
+        assert_eq!(parse_dhms_strict("69d10h40m"), Ok(6_000_000));
+        assert_eq!(parse_dhms_strict("1d1s"), Ok(86_401));
+        assert_eq!(parse_dhms_strict(&format_dhms(u64::MAX)), Ok(u64::MAX));
+
+        assert_eq!(parse_dhms_strict("2h1d"), Err(ParseError::OutOfOrder(3)));
+        assert_eq!(parse_dhms_strict("1s1m"), Err(ParseError::OutOfOrder(3)));
+        assert_eq!(parse_dhms_strict("1d2h1d"), Err(ParseError::OutOfOrder(5)));
+        assert_eq!(parse_dhms_strict("1d1d"), Err(ParseError::DuplicateUnit(3)));
+        assert_eq!(
+            parse_dhms_strict("1d2h2h"),
+            Err(ParseError::DuplicateUnit(5))
+        );
+        assert_eq!(parse_dhms_strict("0s0s"), Err(ParseError::DuplicateUnit(3)));
+        assert_eq!(parse_dhms_strict("1d 2h"), Err(ParseError::UnknownUnit(1)));
+        assert_eq!(parse_dhms_strict("1D"), Err(ParseError::UnknownUnit(1)));
+        assert_eq!(parse_dhms_strict(""), Err(ParseError::Empty));
+        assert_eq!(parse_dhms_strict("1.5s"), Err(ParseError::Fractional(1)));
+        assert_eq!(
+            parse_dhms_strict("213503982334602d"),
+            Err(ParseError::Overflow)
+        );
+
+        for seconds in (0..1_000_000).step_by(997) {
+            assert_eq!(parse_dhms_strict(&format_dhms(seconds)), Ok(seconds));
+        }
+    }
+
+    #[test]
+    fn test_parse_dhms_prefix() {
+        assert_eq!(parse_dhms_prefix("1d2h remaining"), Ok((93_600, 4)));
+        assert_eq!(parse_dhms_prefix("1d2h"), Ok((93_600, 4)));
+        assert_eq!(parse_dhms_prefix("0s"), Ok((0, 2)));
+        assert_eq!(parse_dhms_prefix("69d10h40m: backup"), Ok((6_000_000, 9)));
+        assert_eq!(parse_dhms_prefix("30s, 1 retry"), Ok((30, 3)));
+        assert_eq!(parse_dhms_prefix("1m1s.5"), Ok((61, 4)));
+        assert_eq!(
+            parse_dhms_prefix("1h\u{b5}"),
+            Err(ParseError::UnknownUnit(1))
+        );
+        assert_eq!(parse_dhms_prefix("1h-1s"), Ok((3600, 2)));
+
+        // nothing to consume
+        assert_eq!(parse_dhms_prefix(""), Err(ParseError::Empty));
+        assert_eq!(parse_dhms_prefix(" 1d"), Err(ParseError::MissingNumber(0)));
+        assert_eq!(parse_dhms_prefix("d"), Err(ParseError::MissingNumber(0)));
+        assert_eq!(
+            parse_dhms_prefix("took 1d"),
+            Err(ParseError::MissingNumber(0))
+        );
+
+        // a number has to be followed by a valid unit
+        assert_eq!(
+            parse_dhms_prefix("10 apples"),
+            Err(ParseError::MissingUnit(2))
+        );
+        assert_eq!(
+            parse_dhms_prefix("1d2h3 left"),
+            Err(ParseError::MissingUnit(5))
+        );
+        assert_eq!(
+            parse_dhms_prefix("1d2hours"),
+            Err(ParseError::UnknownUnit(3))
+        );
+        assert_eq!(parse_dhms_prefix("1x"), Err(ParseError::UnknownUnit(1)));
+        assert_eq!(parse_dhms_prefix("1w"), Err(ParseError::UnknownUnit(1)));
+        assert_eq!(parse_dhms_prefix("2h1d"), Err(ParseError::OutOfOrder(3)));
+        assert_eq!(parse_dhms_prefix("1d1d"), Err(ParseError::OutOfOrder(3)));
+        assert_eq!(
+            parse_dhms_prefix("213503982334602d"),
+            Err(ParseError::Overflow)
+        );
+
+        for seconds in (0..1000).map(|i| i * (u64::MAX / 1000)) {
+            let formatted = format_dhms(seconds);
+            let line = alloc::format!("{formatted} elapsed");
+            assert_eq!(parse_dhms_prefix(&line), Ok((seconds, formatted.len())));
+            assert_eq!(
+                parse_dhms_prefix(&formatted),
+                Ok((seconds, formatted.len()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_dhms_lenient() {
+        assert_eq!(parse_dhms_lenient("0s"), Ok(0));
+        assert_eq!(parse_dhms_lenient("69d10h40m"), Ok(6_000_000));
+        assert_eq!(parse_dhms_lenient("1d2h"), Ok(93_600));
+        assert_eq!(parse_dhms_lenient("1D2H"), Ok(93_600));
+        assert_eq!(parse_dhms_lenient("1D 2h"), Ok(93_600));
+        assert_eq!(parse_dhms_lenient("1d,2h"), Ok(93_600));
+        assert_eq!(parse_dhms_lenient("1d, 2h"), Ok(93_600));
+        assert_eq!(parse_dhms_lenient("  1 d ,, 2 H  "), Ok(93_600));
+        assert_eq!(parse_dhms_lenient("\t1d\n2h\r\n"), Ok(93_600));
+        assert_eq!(parse_dhms_lenient("1d 2h 30m 15S"), Ok(95_415));
+
+        // any order, repeated units are added up
+        assert_eq!(parse_dhms_lenient("2h 1d"), Ok(93_600));
+        assert_eq!(parse_dhms_lenient("30m 30m"), Ok(3600));
+
+        assert_eq!(parse_dhms_lenient(""), Err(ParseError::Empty));
+        assert_eq!(parse_dhms_lenient(" , "), Err(ParseError::Empty));
+        assert_eq!(parse_dhms_lenient("d"), Err(ParseError::MissingNumber(0)));
+        assert_eq!(
+            parse_dhms_lenient("1d h"),
+            Err(ParseError::MissingNumber(3))
+        );
+        assert_eq!(parse_dhms_lenient("10"), Err(ParseError::MissingUnit(2)));
+        assert_eq!(parse_dhms_lenient("1 2h"), Err(ParseError::MissingUnit(2)));
+        assert_eq!(
+            parse_dhms_lenient("1d 10 "),
+            Err(ParseError::MissingUnit(6))
+        );
+        assert_eq!(parse_dhms_lenient("1 x"), Err(ParseError::UnknownUnit(2)));
+        assert_eq!(parse_dhms_lenient("1 dh"), Err(ParseError::UnknownUnit(2)));
+        assert_eq!(parse_dhms_lenient("1w"), Err(ParseError::UnknownUnit(1)));
+        assert_eq!(
+            parse_dhms_lenient("1 weeks"),
+            Err(ParseError::UnknownUnit(2))
+        );
+        assert_eq!(parse_dhms_lenient("1d;2h"), Err(ParseError::UnknownUnit(1)));
+        assert_eq!(
+            parse_dhms_lenient("18446744073709551615s 1s"),
+            Err(ParseError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_parse_dhms_lenient_aliases() {
+        assert_eq!(parse_dhms_lenient("1Day 2Hrs 30Mins"), Ok(95_400));
+        assert_eq!(parse_dhms_lenient("1Day2Hrs30Mins"), Ok(95_400));
+        assert_eq!(parse_dhms_lenient("1d2h30m"), Ok(95_400));
+        assert_eq!(
+            parse_dhms_lenient("2 days, 1 hour, 1 minute, 1 second"),
+            Ok(2 * 86_400 + 3661)
+        );
+        assert_eq!(parse_dhms_lenient("1 DAYS 1 HOURS"), Ok(90_000));
+        assert_eq!(parse_dhms_lenient("1hr 1min 1sec"), Ok(3661));
+        assert_eq!(parse_dhms_lenient("2 secs 2s 2 seconds"), Ok(6));
+        assert_eq!(parse_dhms_lenient("90 minutes"), Ok(5400));
+
+        // the whole unit is matched, `m` is minutes and `ms` is not `m` + `s`
+        assert_eq!(parse_dhms_lenient("1m"), Ok(60));
+        assert_eq!(parse_dhms_lenient("1M"), Ok(60));
+        assert_eq!(parse_dhms_lenient("1mins"), Ok(60));
+        assert_eq!(parse_dhms_lenient("1ms"), Err(ParseError::UnknownUnit(1)));
+        assert_eq!(parse_dhms_lenient("1 mn"), Err(ParseError::UnknownUnit(2)));
+        assert_eq!(
+            parse_dhms_lenient("1 hourss"),
+            Err(ParseError::UnknownUnit(2))
+        );
+        assert_eq!(parse_dhms_lenient("1 da"), Err(ParseError::UnknownUnit(2)));
+        assert_eq!(
+            parse_dhms_lenient("1 d ay"),
+            Err(ParseError::MissingNumber(4))
+        );
+
+        // the strict parser only accepts the short suffixes
+        assert_eq!(parse_dhms("1day"), Err(ParseError::UnknownUnit(1)));
+    }
+
+    #[test]
+    fn test_parse_wdhms_lenient() {
+        assert_eq!(parse_wdhms_lenient("0s"), Ok(0));
+        assert_eq!(parse_wdhms_lenient("1w"), Ok(604_800));
+        assert_eq!(parse_wdhms_lenient("1 Week"), Ok(604_800));
+        assert_eq!(parse_wdhms_lenient("2 weeks, 1 day"), Ok(1_296_000));
+        assert_eq!(parse_wdhms_lenient("9w 6d 10h 40m"), Ok(6_000_000));
+        assert_eq!(
+            parse_wdhms_lenient("40 mins 10 hrs 6 days 9 weeks"),
+            Ok(6_000_000)
+        );
+        assert_eq!(
+            parse_wdhms_lenient(&format_wdhms(123_456_789)),
+            Ok(123_456_789)
+        );
+
+        assert_eq!(parse_wdhms_lenient(" "), Err(ParseError::Empty));
+        assert_eq!(parse_wdhms_lenient("1 wk"), Err(ParseError::UnknownUnit(2)));
+        assert_eq!(parse_wdhms_lenient("1ms"), Err(ParseError::UnknownUnit(1)));
+        assert_eq!(
+            parse_wdhms_lenient("30500568904944 weeks"),
+            Err(ParseError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_parse_wdhms() {
+        assert_eq!(parse_wdhms("0s"), Ok(0));
+        assert_eq!(parse_wdhms("61s"), Ok(61));
+        assert_eq!(parse_wdhms("1w"), Ok(604_800));
+        assert_eq!(parse_wdhms("9w6d10h40m"), Ok(6_000_000));
+        assert_eq!(parse_wdhms("7101w3d6h28m15s"), Ok(4_294_967_295));
+        assert_eq!(parse_wdhms(&format_wdhms(123_456_789)), Ok(123_456_789));
+
+        // non-canonical values are summed as is
+        assert_eq!(parse_wdhms("1w8d"), Ok(15 * 86400));
+        assert_eq!(parse_wdhms("0w14d"), Ok(2 * 604_800));
+        assert_eq!(parse_wdhms("25h61m"), Ok(25 * 3600 + 61 * 60));
+
+        assert_eq!(parse_wdhms(""), Err(ParseError::Empty));
+        assert_eq!(parse_wdhms("1d1w"), Err(ParseError::OutOfOrder(3)));
+        assert_eq!(parse_wdhms("1y"), Err(ParseError::UnknownUnit(1)));
+        assert_eq!(parse_wdhms("30500568904944w"), Err(ParseError::Overflow));
+    }
+
+    #[test]
+    fn test_parse_ns_decimal() {
+        assert_eq!(parse_ns("1.5s"), Ok(1_500_000_000));
+        assert_eq!(parse_ns("0.5s"), Ok(500_000_000));
+        assert_eq!(parse_ns("0.000000001s"), Ok(1));
+        assert_eq!(parse_ns("3.129723s"), Ok(3_129_723_000));
+        assert_eq!(parse_ns("40.010100001s"), Ok(40_010_100_001));
+        assert_eq!(
+            parse_ns("1157d9h46m40.010100001s"),
+            Ok(100_000_000_010_100_001)
+        );
+        assert_eq!(parse_ns("1.0s"), Ok(1_000_000_000));
+        assert_eq!(parse_ns("1.5s1ns"), Ok(1_500_000_001));
+        assert_eq!(parse_ns("18446744073.709551615s"), Ok(u64::MAX));
+        assert_eq!(
+            parse_ns(&format_ns_decimal(100_000_000_010_100_001_usize)),
+            Ok(100_000_000_010_100_001)
+        );
+
+        assert_eq!(parse_ns("1.5m"), Err(ParseError::Fractional(1)));
+        assert_eq!(parse_ns("1s1.5ms"), Err(ParseError::Fractional(3)));
+        assert_eq!(
+            parse_ns("0.0000000001s"),
+            Err(ParseError::ExcessPrecision(11))
+        );
+        assert_eq!(parse_ns("1.s"), Err(ParseError::UnknownUnit(1)));
+        assert_eq!(parse_ns(".5s"), Err(ParseError::MissingNumber(0)));
+        assert_eq!(parse_ns("1.5"), Err(ParseError::MissingUnit(3)));
+        assert_eq!(
+            parse_ns("18446744073.709551616s"),
+            Err(ParseError::Overflow)
+        );
+
+        // only parse_ns takes decimals
+        assert_eq!(parse_dhms("1.5s"), Err(ParseError::Fractional(1)));
+        assert_eq!(parse_wdhms("1w1.5d"), Err(ParseError::Fractional(3)));
+    }
+
+    #[test]
+    fn test_parse_ns_decimal_roundtrip() {
+        // leading zeros of the fraction are kept
+        assert_eq!(format_ns_decimal(40_010_100_001_u64), "40.010100001s");
+        assert_eq!(format_ns_decimal_trimmed(10_100_000_u64), "0.0101s");
+        assert_eq!(parse_ns(".010100001s"), Err(ParseError::MissingNumber(0)));
+        assert_eq!(parse_ns("0.010100001s"), Ok(10_100_001));
+
+        let fractions = (0..9).map(|zeros| 10_u64.pow(zeros)).chain([
+            0,
+            10_100_001,
+            999_999_999,
+            100_000_000,
+            1_000_001,
+        ]);
+        let seconds = [0_u64, 1, 40, 60, 3600, 86_400, 100_000_000, 18_446_744_072];
+        for fraction in fractions {
+            for &secs in &seconds {
+                let n = secs * 1_000_000_000 + fraction;
+                assert_eq!(parse_ns(&format_ns_decimal(n)), Ok(n));
+                assert_eq!(parse_ns(&format_ns_decimal_trimmed(n)), Ok(n));
+            }
+        }
+        for n in (0..997).map(|i| i * (u64::MAX / 997)).chain([u64::MAX]) {
+            assert_eq!(parse_ns(&format_ns_decimal(n)), Ok(n));
+            assert_eq!(parse_ns(&format_ns_decimal_trimmed(n)), Ok(n));
+        }
+    }
+
+    #[test]
+    fn test_parse_ns() {
+        assert_eq!(parse_ns("0ns"), Ok(0));
+        assert_eq!(parse_ns("1ns"), Ok(1));
+        assert_eq!(parse_ns("1\u{b5}s"), Ok(1000));
+        assert_eq!(parse_ns("1us"), Ok(1000));
+        assert_eq!(parse_ns("1ms1ns"), Ok(1_000_001));
+        assert_eq!(parse_ns("1s"), Ok(1_000_000_000));
+        assert_eq!(parse_ns("1m40s10ns"), Ok(100_000_000_010));
+        assert_eq!(parse_ns("1m1ms"), Ok(60_001_000_000));
+        assert_eq!(parse_ns("3s129\u{b5}s723ns"), Ok(3_000_129_723));
+        assert_eq!(
+            parse_ns("1157d9h46m40s10ms100\u{b5}s1ns"),
+            Ok(100_000_000_010_100_001)
+        );
+        assert_eq!(
+            parse_ns(&format_ns(100_000_000_010_000_001_usize)),
+            Ok(100_000_000_010_000_001)
+        );
+
+        assert_eq!(parse_ns(""), Err(ParseError::Empty));
+        assert_eq!(parse_ns("1ms1s"), Err(ParseError::OutOfOrder(4)));
+        assert_eq!(parse_ns("1us1\u{b5}s"), Err(ParseError::OutOfOrder(4)));
+        assert_eq!(parse_ns("1\u{b5}"), Err(ParseError::UnknownUnit(1)));
+        assert_eq!(parse_ns("1mss"), Err(ParseError::UnknownUnit(1)));
+        assert_eq!(parse_ns("18446744073709551615ns"), Ok(u64::MAX));
+        assert_eq!(
+            parse_ns("18446744073709551616ns"),
+            Err(ParseError::Overflow)
+        );
+        assert_eq!(parse_ns("18446744074s"), Err(ParseError::Overflow));
+        assert_eq!(
+            parse_ns("18446744073s709551616ns"),
+            Err(ParseError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_parse_to_std_duration() {
+        assert_eq!(parse_to_std_duration("0ns"), Ok(Duration::ZERO));
+        assert_eq!(parse_to_std_duration("0s"), Ok(Duration::ZERO));
+        assert_eq!(
+            parse_to_std_duration("1500ms"),
+            Ok(Duration::from_millis(1500))
+        );
+        assert_eq!(
+            parse_to_std_duration("1s500ms"),
+            Ok(Duration::from_millis(1500))
+        );
+        assert_eq!(
+            parse_to_std_duration("1.5s"),
+            Ok(Duration::from_millis(1500))
+        );
+        assert_eq!(
+            parse_to_std_duration("69d10h40m1ms1ns"),
+            Ok(Duration::new(6_000_000, 1_000_001))
+        );
+        assert_eq!(
+            parse_to_std_duration("213503d23h34m33s709ms551\u{b5}s615ns"),
+            Ok(Duration::from_nanos(u64::MAX))
+        );
+        assert_eq!(
+            parse_to_std_duration(&format_duration(Duration::from_millis(1500))),
+            Ok(Duration::from_millis(1500))
+        );
+        for nanos in (0..997).map(|i| i * (u64::MAX / 997)) {
+            let duration = Duration::from_nanos(nanos);
+            assert_eq!(
+                parse_to_std_duration(&format_duration(duration)),
+                Ok(duration)
+            );
+        }
+
+        assert_eq!(parse_to_std_duration(""), Err(ParseError::Empty));
+        assert_eq!(parse_to_std_duration("1x"), Err(ParseError::UnknownUnit(1)));
+        assert_eq!(
+            parse_to_std_duration("213503d23h34m33s709ms551\u{b5}s616ns"),
+            Err(ParseError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        let err = parse_dhms("1d2x").unwrap_err();
+        assert_eq!(err, ParseError::UnknownUnit(3));
+        assert_eq!(err.to_string(), "unknown unit at byte 3");
+        assert_eq!(
+            parse_dhms("10m1h").unwrap_err().to_string(),
+            "unit out of order at byte 4"
+        );
+        assert_eq!(
+            parse_dhms("10").unwrap_err().to_string(),
+            "missing unit after number at byte 2"
+        );
+        assert_eq!(parse_dhms("").unwrap_err().to_string(), "empty duration");
+        assert_eq!(
+            ParseError::Overflow.to_string(),
+            "duration out of range for u64"
+        );
+        assert_eq!(
+            ParseError::MissingNumber(0).to_string(),
+            "missing number before unit at byte 0"
+        );
+        assert_eq!(
+            ParseError::DuplicateUnit(3).to_string(),
+            "duplicate unit at byte 3"
+        );
+        assert_eq!(
+            ParseError::Fractional(4).to_string(),
+            "unexpected fractional part at byte 4"
+        );
+        assert_eq!(
+            ParseError::ExcessPrecision(12).to_string(),
+            "fraction too precise at byte 12"
+        );
+        assert_eq!(
+            ParseError::InvalidFormat(0).to_string(),
+            "invalid format at byte 0"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_error_boxed() {
+        fn parse(input: &str) -> Result<u64, std::boxed::Box<dyn std::error::Error>> {
+            Ok(parse_dhms(input)?)
+        }
+        assert_eq!(parse("1m1s").unwrap(), 61);
+        assert_eq!(
+            parse("1x").unwrap_err().to_string(),
+            "unknown unit at byte 1"
+        );
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::{
+        parse_dhms, parse_dhms_lenient, parse_dhms_strict, parse_ns, parse_wdhms,
+        parse_wdhms_lenient,
+    };
+    use crate::{
+        format_dhms, format_dhms_sep, format_ns, format_ns_ascii, format_ns_decimal,
+        format_ns_decimal_trimmed, format_wdhms,
+    };
+    use proptest::prelude::*;
+    use proptest::test_runner::RngSeed;
+
+    /// Fixed seed so a failure shows up on every run, change it to explore
+    /// other inputs
+    const SEED: u64 = 0x00c0_ffee;
+
+    fn config() -> ProptestConfig {
+        ProptestConfig {
+            cases: 4096,
+            rng_seed: RngSeed::Fixed(SEED),
+            ..ProptestConfig::default()
+        }
+    }
+
+    /// Any `u64`, biased towards small values and values close to a multiple
+    /// of one of the units where off by one errors show up
+    fn duration() -> impl Strategy<Value = u64> {
+        let units = prop::sample::select(&[
+            1_u64,
+            1000,
+            1_000_000,
+            1_000_000_000,
+            60,
+            3600,
+            86_400,
+            604_800,
+            60 * 1_000_000_000,
+            3600 * 1_000_000_000,
+            86_400 * 1_000_000_000,
+        ]);
+        prop_oneof![
+            any::<u64>(),
+            0..100_000_u64,
+            (units, 0..1_000_000_u64, -1..=1_i64)
+                .prop_map(|(unit, n, delta)| unit.saturating_mul(n).saturating_add_signed(delta)),
+            Just(u64::MAX),
+        ]
+    }
+
+    proptest! {
+        #![proptest_config(config())]
+
+        #[test]
+        fn roundtrip_dhms(n in duration()) {
+            prop_assert_eq!(parse_dhms(&format_dhms(n)), Ok(n));
+            prop_assert_eq!(parse_dhms_strict(&format_dhms(n)), Ok(n));
+            prop_assert_eq!(parse_dhms_lenient(&format_dhms_sep(n, ", ")), Ok(n));
+        }
+
+        #[test]
+        fn roundtrip_wdhms(n in duration()) {
+            prop_assert_eq!(parse_wdhms(&format_wdhms(n)), Ok(n));
+            prop_assert_eq!(parse_wdhms_lenient(&format_wdhms(n)), Ok(n));
+        }
+
+        #[test]
+        fn roundtrip_ns(n in duration()) {
+            prop_assert_eq!(parse_ns(&format_ns(n)), Ok(n));
+            prop_assert_eq!(parse_ns(&format_ns_ascii(n)), Ok(n));
+            prop_assert_eq!(parse_ns(&format_ns_decimal(n)), Ok(n));
+            prop_assert_eq!(parse_ns(&format_ns_decimal_trimmed(n)), Ok(n));
+        }
+
+        #[test]
+        fn roundtrip_ns_decimal_leading_zeros(
+            secs in 0..18_446_744_073_u64,
+            zeros in 0..9_u32,
+            digits in 0..1_000_000_000_u64,
+        ) {
+            // a fraction with at least `zeros` leading zeros
+            let n = secs * 1_000_000_000 + digits / 10_u64.pow(zeros);
+            prop_assert_eq!(parse_ns(&format_ns_decimal(n)), Ok(n));
+            prop_assert_eq!(parse_ns(&format_ns_decimal_trimmed(n)), Ok(n));
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Serialize a `u64` number of seconds as a compound duration string
+//!
+//! Example:
+//!```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "compound_duration::serde_secs")]
+//!     timeout: u64,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{"timeout":"1h30m"}"#).unwrap();
+//! assert_eq!(config.timeout, 5400);
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"timeout":"1h30m"}"#);
+//!```
+
+use crate::{format_dhms, CompoundDuration};
+use ::serde::{Deserialize, Deserializer, Serializer};
+
+/// Serialize seconds as a compound duration string, e.g. `"1d2h"`
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails.
+#[allow(clippy::trivially_copy_pass_by_ref)]
+pub fn serialize<S: Serializer>(seconds: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format_dhms(*seconds))
+}
+
+/// Deserialize seconds from a compound duration string, e.g. `"1d2h"`
+///
+/// # Errors
+///
+/// Returns an error if the value is not a valid compound duration.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    CompoundDuration::deserialize(deserializer).map(CompoundDuration::as_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "crate::serde_secs")]
+        timeout: u64,
+    }
+
+    #[test]
+    fn test_serde_secs() {
+        let config = Config { timeout: 93_600 };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"timeout":"1d2h"}"#);
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+        assert_eq!(
+            serde_json::from_str::<Config>(r#"{"timeout":"0s"}"#).unwrap(),
+            Config { timeout: 0 }
+        );
+        assert!(serde_json::from_str::<Config>(r#"{"timeout":"2h1d"}"#).is_err());
+    }
+}
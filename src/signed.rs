@@ -0,0 +1,335 @@
+use crate::{format_approx, write_dhms};
+use alloc::format;
+use alloc::string::String;
+
+/// Sign of a normalized value, see [`normalize`]
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Sign {
+    Negative,
+    Zero,
+    Positive,
+}
+
+/// Split `seconds` into its sign and magnitude
+///
+/// Zero always has [`Sign::Zero`] however it was computed, so the signed
+/// formatters can never write `"-0s"`.
+const fn normalize(seconds: i64) -> (Sign, u64) {
+    let sign = if seconds < 0 {
+        Sign::Negative
+    } else if seconds == 0 {
+        Sign::Zero
+    } else {
+        Sign::Positive
+    };
+    (sign, seconds.unsigned_abs())
+}
+
+/// Convert signed seconds to compound duration (days, hours, minutes, seconds)
+///
+/// Negative values are prefixed with `-`, zero is `"0s"` without a sign.
+/// `i64::MIN` is formatted correctly, the magnitude is taken with
+/// [`i64::unsigned_abs`] so it can't overflow.
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_signed;
+///
+/// assert_eq!(format_dhms_signed(-61), "-1m1s");
+/// assert_eq!(format_dhms_signed(61), "1m1s");
+///```
+#[must_use]
+pub fn format_dhms_signed(seconds: i64) -> String {
+    format_dhms_signed_with(seconds, SignStyle::OnlyNegative)
+}
+
+/// When the sign is written by [`format_dhms_signed_with`]
+///
+/// Zero never has a sign, whatever the style.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SignStyle {
+    /// `-` for negative values only, same as [`format_dhms_signed`]
+    #[default]
+    OnlyNegative,
+    /// `-` for negative values and `+` for positive values
+    Always,
+    /// No sign, only the magnitude is written
+    Never,
+}
+
+/// Convert signed seconds to compound duration (days, hours, minutes,
+/// seconds) with the sign written according to `style`
+///
+/// Example:
+///```
+/// use compound_duration::{format_dhms_signed_with, SignStyle};
+///
+/// assert_eq!(format_dhms_signed_with(61, SignStyle::Always), "+1m1s");
+/// assert_eq!(format_dhms_signed_with(-61, SignStyle::Always), "-1m1s");
+/// assert_eq!(format_dhms_signed_with(-61, SignStyle::Never), "1m1s");
+/// assert_eq!(format_dhms_signed_with(0, SignStyle::Always), "0s");
+///```
+#[must_use]
+pub fn format_dhms_signed_with(seconds: i64, style: SignStyle) -> String {
+    let (sign, magnitude) = normalize(seconds);
+    let mut compound_duration = String::new();
+    match (style, sign) {
+        (SignStyle::OnlyNegative | SignStyle::Always, Sign::Negative) => {
+            compound_duration.push('-');
+        }
+        (SignStyle::Always, Sign::Positive) => compound_duration.push('+'),
+        _ => {}
+    }
+    // writing to a String never fails
+    write_dhms(&mut compound_duration, magnitude).unwrap();
+    compound_duration
+}
+
+/// Convert the seconds elapsed from `then` to `now` to compound duration (days,
+/// hours, minutes, seconds)
+///
+/// Meant for Unix timestamps. If `then` is after `now` the difference is
+/// clamped to zero and `"0s"` is returned, use [`format_between`] to keep the
+/// sign instead.
+///
+/// Example:
+///```
+/// use compound_duration::format_since;
+///
+/// assert_eq!(format_since(1_600_000_000, 1_606_000_000), "69d10h40m");
+/// assert_eq!(format_since(1_606_000_000, 1_600_000_000), "0s");
+///```
+#[must_use]
+pub fn format_since(then: u64, now: u64) -> String {
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_dhms(&mut compound_duration, now.saturating_sub(then)).unwrap();
+    compound_duration
+}
+
+/// Convert the seconds left from `now` until `deadline` to compound duration
+/// (days, hours, minutes, seconds)
+///
+/// Meant for countdowns on Unix timestamps. Once the deadline is reached or
+/// has passed the remaining time is clamped to zero and `"0s"` is returned,
+/// never a negative or wrapped value.
+///
+/// Example:
+///```
+/// use compound_duration::format_remaining;
+///
+/// assert_eq!(format_remaining(1_600_003_661, 1_600_000_000), "1h1m1s");
+/// assert_eq!(format_remaining(1_600_000_000, 1_600_000_000), "0s");
+/// assert_eq!(format_remaining(1_600_000_000, 1_600_003_661), "0s");
+///```
+#[must_use]
+pub fn format_remaining(deadline_secs: u64, now_secs: u64) -> String {
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_dhms(
+        &mut compound_duration,
+        deadline_secs.saturating_sub(now_secs),
+    )
+    .unwrap();
+    compound_duration
+}
+
+/// Convert a signed offset in seconds to relative wording, `"3h ago"` or
+/// `"in 3h"`
+///
+/// A positive `delta_secs` is in the past and a negative one in the future,
+/// as returned by `now - timestamp`. Zero is `"now"`. The duration is
+/// approximated to its largest unit like [`format_approx`], so anything under
+/// a minute is `"<1m ago"` or `"in <1m"`.
+///
+/// Example:
+///```
+/// use compound_duration::format_relative;
+///
+/// assert_eq!(format_relative(10_800), "3h ago");
+/// assert_eq!(format_relative(-10_800), "in 3h");
+/// assert_eq!(format_relative(0), "now");
+///```
+#[must_use]
+pub fn format_relative(delta_secs: i64) -> String {
+    let (sign, magnitude) = normalize(delta_secs);
+    match sign {
+        Sign::Zero => String::from("now"),
+        Sign::Positive => format!("{} ago", format_approx(magnitude)),
+        Sign::Negative => format!("in {}", format_approx(magnitude)),
+    }
+}
+
+/// Convert the signed difference `b - a` to compound duration (days, hours,
+/// minutes, seconds)
+///
+/// The result is prefixed with `-` when `a` is after `b`. The whole `u64`
+/// range is supported, the difference never overflows.
+///
+/// Example:
+///```
+/// use compound_duration::format_between;
+///
+/// assert_eq!(format_between(1_600_000_000, 1_606_000_000), "69d10h40m");
+/// assert_eq!(format_between(1_606_000_000, 1_600_000_000), "-69d10h40m");
+///```
+#[must_use]
+pub fn format_between(a: u64, b: u64) -> String {
+    let mut compound_duration = String::new();
+    if a > b {
+        compound_duration.push('-');
+    }
+    // writing to a String never fails
+    write_dhms(&mut compound_duration, a.abs_diff(b)).unwrap();
+    compound_duration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        format_between, format_dhms_signed, format_dhms_signed_with, format_relative,
+        format_remaining, format_since, SignStyle,
+    };
+    use crate::format_approx;
+    use alloc::format;
+
+    #[test]
+    fn test_format_dhms_signed() {
+        assert_eq!(format_dhms_signed(0), "0s");
+        assert_eq!(format_dhms_signed(-0), "0s");
+        assert_eq!(format_dhms_signed(1), "1s");
+        assert_eq!(format_dhms_signed(-1), "-1s");
+        assert_eq!(format_dhms_signed(61), "1m1s");
+        assert_eq!(format_dhms_signed(-61), "-1m1s");
+        assert_eq!(format_dhms_signed(-6_000_000), "-69d10h40m");
+        assert_eq!(format_dhms_signed(i64::MAX), "106751991167300d15h30m7s");
+        assert_eq!(format_dhms_signed(i64::MIN), "-106751991167300d15h30m8s");
+    }
+
+    #[test]
+    fn test_negative_zero() {
+        use SignStyle::{Always, Never};
+
+        // deltas that cancel out to exactly zero never keep a sign
+        let deltas: [i64; 4] = [-30, 10, -10, 30];
+        let total: i64 = deltas.iter().sum();
+        assert_eq!(format_dhms_signed(total), "0s");
+        assert_eq!(format_dhms_signed_with(total, Always), "0s");
+        assert_eq!(format_dhms_signed_with(total, Never), "0s");
+    }
+
+    #[test]
+    fn test_format_dhms_signed_with() {
+        use SignStyle::{Always, Never, OnlyNegative};
+
+        let cases = [
+            (0, ["0s", "0s", "0s"]),
+            (1, ["1s", "+1s", "1s"]),
+            (-1, ["-1s", "-1s", "1s"]),
+            (61, ["1m1s", "+1m1s", "1m1s"]),
+            (-61, ["-1m1s", "-1m1s", "1m1s"]),
+            (
+                i64::MAX,
+                [
+                    "106751991167300d15h30m7s",
+                    "+106751991167300d15h30m7s",
+                    "106751991167300d15h30m7s",
+                ],
+            ),
+            (
+                i64::MIN,
+                [
+                    "-106751991167300d15h30m8s",
+                    "-106751991167300d15h30m8s",
+                    "106751991167300d15h30m8s",
+                ],
+            ),
+        ];
+        for (seconds, [only_negative, always, never]) in cases {
+            assert_eq!(
+                format_dhms_signed_with(seconds, OnlyNegative),
+                only_negative
+            );
+            assert_eq!(format_dhms_signed_with(seconds, Always), always);
+            assert_eq!(format_dhms_signed_with(seconds, Never), never);
+        }
+        assert_eq!(SignStyle::default(), OnlyNegative);
+
+        for seconds in (-1_000_000..1_000_000).step_by(997) {
+            assert_eq!(
+                format_dhms_signed_with(seconds, OnlyNegative),
+                format_dhms_signed(seconds)
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_since() {
+        assert_eq!(format_since(0, 0), "0s");
+        assert_eq!(format_since(100, 100), "0s");
+        assert_eq!(format_since(100, 161), "1m1s");
+        assert_eq!(format_since(1_600_000_000, 1_606_000_000), "69d10h40m");
+        assert_eq!(format_since(0, u64::MAX), "213503982334601d7h15s");
+
+        // then is after now
+        assert_eq!(format_since(161, 100), "0s");
+        assert_eq!(format_since(u64::MAX, 0), "0s");
+    }
+
+    #[test]
+    fn test_format_remaining() {
+        assert_eq!(format_remaining(161, 100), "1m1s");
+        assert_eq!(format_remaining(1_606_000_000, 1_600_000_000), "69d10h40m");
+        assert_eq!(format_remaining(u64::MAX, 0), "213503982334601d7h15s");
+        assert_eq!(format_remaining(1, 0), "1s");
+
+        // deadline reached
+        assert_eq!(format_remaining(0, 0), "0s");
+        assert_eq!(format_remaining(100, 100), "0s");
+        assert_eq!(format_remaining(u64::MAX, u64::MAX), "0s");
+
+        // deadline passed
+        assert_eq!(format_remaining(100, 101), "0s");
+        assert_eq!(format_remaining(100, 161), "0s");
+        assert_eq!(format_remaining(0, u64::MAX), "0s");
+    }
+
+    #[test]
+    fn test_format_relative() {
+        assert_eq!(format_relative(0), "now");
+        assert_eq!(format_relative(1), "<1m ago");
+        assert_eq!(format_relative(-1), "in <1m");
+        assert_eq!(format_relative(59), "<1m ago");
+        assert_eq!(format_relative(60), "1m ago");
+        assert_eq!(format_relative(-60), "in 1m");
+        assert_eq!(format_relative(10_800), "3h ago");
+        assert_eq!(format_relative(-10_800), "in 3h");
+        assert_eq!(format_relative(5400), "2h ago");
+        assert_eq!(format_relative(86_340), "1d ago");
+        assert_eq!(format_relative(-6_000_000), "in 69d");
+        assert_eq!(format_relative(i64::MAX), "106751991167301d ago");
+        assert_eq!(format_relative(i64::MIN), "in 106751991167301d");
+
+        for delta in (1..1_000_000).step_by(997) {
+            assert_eq!(
+                format_relative(delta),
+                format!("{} ago", format_approx(delta))
+            );
+            assert_eq!(
+                format_relative(-delta),
+                format!("in {}", format_approx(delta))
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_between() {
+        assert_eq!(format_between(0, 0), "0s");
+        assert_eq!(format_between(100, 100), "0s");
+        assert_eq!(format_between(100, 161), "1m1s");
+        assert_eq!(format_between(161, 100), "-1m1s");
+        assert_eq!(format_between(1_606_000_000, 1_600_000_000), "-69d10h40m");
+        assert_eq!(format_between(0, u64::MAX), "213503982334601d7h15s");
+        assert_eq!(format_between(u64::MAX, 0), "-213503982334601d7h15s");
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{to_u64, write_dhms};
+use core::convert::{TryFrom, TryInto};
+use core::fmt::Debug;
+use core::ops::BitAnd;
+use smallstr::SmallString;
+
+/// Convert seconds to compound duration (days, hours, minutes, seconds) in a
+/// stack allocated string
+///
+/// Same output as [`format_dhms`](crate::format_dhms) without touching the
+/// heap. The longest possible output, `u64::MAX` seconds, is 25 bytes so it
+/// always fits in the inline buffer, the string only spills to the heap if it
+/// is grown afterwards.
+///
+/// Requires the `smallstr` feature.
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_small;
+///
+/// let small = format_dhms_small(6_000_000);
+/// assert_eq!(small, "69d10h40m");
+/// assert!(!small.spilled());
+///```
+#[must_use]
+pub fn format_dhms_small<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+) -> SmallString<[u8; 32]>
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let mut compound_duration = SmallString::new();
+    // writing to a SmallString never fails
+    write_dhms(&mut compound_duration, to_u64(seconds)).unwrap();
+    compound_duration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_dhms_small;
+    use crate::format_dhms;
+
+    #[test]
+    fn test_format_dhms_small() {
+        assert_eq!(format_dhms_small(0), "0s");
+        assert_eq!(format_dhms_small(61), "1m1s");
+        assert_eq!(format_dhms_small(6_000_000), "69d10h40m");
+        for seconds in (0..10_000_000).step_by(9973) {
+            assert_eq!(format_dhms_small(seconds).as_str(), format_dhms(seconds));
+        }
+
+        // the longest output still fits inline
+        let max = format_dhms_small(u64::MAX - 43_200);
+        assert_eq!(max, "213503982334600d19h15s");
+        assert!(!max.spilled());
+        let longest = format_dhms_small(213_503_982_334_600 * 86_400 + 86_399_u64);
+        assert_eq!(longest, "213503982334600d23h59m59s");
+        assert_eq!(longest.len(), 25);
+        assert!(!longest.spilled());
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{subsec_parts, to_u64, NANOS_PER_SECOND};
+use alloc::string::String;
+use core::convert::{TryFrom, TryInto};
+use core::fmt::{Debug, Write};
+use core::ops::BitAnd;
+
+/// Convert nanoseconds under a second to compound duration (ms, µs, ns)
+///
+/// Meant for microbenchmarks where every timing is below a second, only
+/// milliseconds, microseconds and nanoseconds are computed. Anything from one
+/// second up is ignored, only `nanos % 1_000_000_000` is formatted, so below a
+/// second the output is the same as [`format_ns`](crate::format_ns).
+///
+/// Example:
+///```
+/// use compound_duration::format_subsecond;
+///
+/// assert_eq!(format_subsecond(129_723_u64), "129\u{b5}s723ns");
+/// assert_eq!(format_subsecond(999_999_999_u64), "999ms999\u{b5}s999ns");
+/// // whole seconds are ignored
+/// assert_eq!(format_subsecond(3_000_129_723_u64), "129\u{b5}s723ns");
+///```
+#[must_use]
+pub fn format_subsecond<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(nanos: T) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let nanos = to_u64(nanos) % NANOS_PER_SECOND as u64;
+    if nanos == 0 {
+        return String::from("0ns");
+    }
+
+    let mut compound_duration = String::new();
+    for (value, unit) in subsec_parts(nanos).iter().filter(|(value, _)| *value != 0) {
+        // writing to a String never fails
+        write!(compound_duration, "{value}{}", unit.suffix()).unwrap();
+    }
+
+    compound_duration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_subsecond;
+    use crate::format_ns;
+
+    #[test]
+    fn test_format_subsecond() {
+        assert_eq!(format_subsecond(0), "0ns");
+        assert_eq!(format_subsecond(1), "1ns");
+        assert_eq!(format_subsecond(999), "999ns");
+        assert_eq!(format_subsecond(1000), "1\u{b5}s");
+        assert_eq!(format_subsecond(1001), "1\u{b5}s1ns");
+        assert_eq!(format_subsecond(1_000_000), "1ms");
+        assert_eq!(format_subsecond(1_000_001), "1ms1ns");
+        assert_eq!(format_subsecond(129_723), "129\u{b5}s723ns");
+        assert_eq!(format_subsecond(999_999_999), "999ms999\u{b5}s999ns");
+
+        // anything from one second up is ignored
+        assert_eq!(format_subsecond(1_000_000_000), "0ns");
+        assert_eq!(format_subsecond(3_000_129_723_u64), "129\u{b5}s723ns");
+        assert_eq!(format_subsecond(u64::MAX), "709ms551\u{b5}s615ns");
+
+        for nanos in (1..1_000_000_000).step_by(9973).chain([999_999_999]) {
+            assert_eq!(format_subsecond(nanos), format_ns(nanos));
+        }
+    }
+}
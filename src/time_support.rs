@@ -0,0 +1,168 @@
+use crate::{parse_ns, ParseError, NANOS_PER_SECOND, NS_FORMATTER};
+use alloc::string::String;
+use time::Duration;
+
+/// Convert a [`time::Duration`] to compound duration (days, hours, minutes,
+/// seconds, ms, µs, ns)
+///
+/// The output goes down to nanoseconds like [`format_ns`](crate::format_ns).
+/// Negative durations are prefixed with `-`, zero is `"0ns"` without a sign.
+///
+/// Requires the `time` feature.
+///
+/// Example:
+///```
+/// use compound_duration::format_time_duration;
+/// use time::Duration;
+///
+/// assert_eq!(format_time_duration(Duration::seconds(6_000_000)), "69d10h40m");
+/// assert_eq!(format_time_duration(Duration::milliseconds(-1500)), "-1s500ms");
+///```
+#[must_use]
+pub fn format_time_duration(d: Duration) -> String {
+    let nanos = d.whole_nanoseconds();
+
+    let mut compound_duration = String::new();
+    if nanos < 0 {
+        compound_duration.push('-');
+    }
+    // writing to a String never fails
+    NS_FORMATTER
+        .write_total(&mut compound_duration, nanos.unsigned_abs(), 1)
+        .unwrap();
+    compound_duration
+}
+
+/// Parse a compound duration (days, hours, minutes, seconds, ms, µs, ns) into a
+/// [`time::Duration`]
+///
+/// This is the inverse of [`format_time_duration`], the units are the same as
+/// in [`parse_ns`] with an optional leading `-` for negative durations.
+///
+/// Requires the `time` feature.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] for the same reasons as [`parse_ns`], including
+/// [`ParseError::Overflow`] when the magnitude doesn't fit in `u64`
+/// nanoseconds (about 584 years).
+///
+/// Example:
+///```
+/// use compound_duration::parse_to_time_duration;
+/// use time::Duration;
+///
+/// assert_eq!(parse_to_time_duration("1m1s"), Ok(Duration::seconds(61)));
+/// assert_eq!(
+///     parse_to_time_duration("-1s500ms"),
+///     Ok(Duration::milliseconds(-1500))
+/// );
+///```
+pub fn parse_to_time_duration(input: &str) -> Result<Duration, ParseError> {
+    let (negative, body) = match input.strip_prefix('-') {
+        Some("") => return Err(ParseError::MissingNumber(1)),
+        Some(body) => (true, body),
+        None => (false, input),
+    };
+    let offset = input.len() - body.len();
+
+    let nanos = parse_ns(body).map_err(|e| e.shifted(offset))?;
+    let (secs, subsec) = (
+        nanos / NANOS_PER_SECOND as u64,
+        nanos % NANOS_PER_SECOND as u64,
+    );
+    // u64::MAX nanoseconds is well within the range of `i64` seconds
+    let d = Duration::new(secs as i64, subsec as i32);
+    Ok(if negative { -d } else { d })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_time_duration, parse_to_time_duration};
+    use crate::ParseError;
+    use time::Duration;
+
+    #[test]
+    fn test_format_time_duration() {
+        assert_eq!(format_time_duration(Duration::ZERO), "0ns");
+        assert_eq!(format_time_duration(Duration::nanoseconds(1)), "1ns");
+        assert_eq!(format_time_duration(Duration::seconds(61)), "1m1s");
+        assert_eq!(
+            format_time_duration(Duration::seconds(6_000_000)),
+            "69d10h40m"
+        );
+        assert_eq!(
+            format_time_duration(Duration::nanoseconds(3_000_129_723)),
+            "3s129\u{b5}s723ns"
+        );
+
+        assert_eq!(format_time_duration(Duration::nanoseconds(-1)), "-1ns");
+        assert_eq!(format_time_duration(Duration::milliseconds(-250)), "-250ms");
+        assert_eq!(
+            format_time_duration(Duration::seconds(-6_000_000)),
+            "-69d10h40m"
+        );
+        assert_eq!(
+            format_time_duration(Duration::MAX),
+            "106751991167300d15h30m7s999ms999\u{b5}s999ns"
+        );
+        assert_eq!(
+            format_time_duration(Duration::MIN),
+            "-106751991167300d15h30m8s999ms999\u{b5}s999ns"
+        );
+    }
+
+    #[test]
+    fn test_parse_to_time_duration() {
+        assert_eq!(parse_to_time_duration("0ns"), Ok(Duration::ZERO));
+        assert_eq!(parse_to_time_duration("-0ns"), Ok(Duration::ZERO));
+        assert_eq!(parse_to_time_duration("1m1s"), Ok(Duration::seconds(61)));
+        assert_eq!(
+            parse_to_time_duration("3s129\u{b5}s723ns"),
+            Ok(Duration::nanoseconds(3_000_129_723))
+        );
+        assert_eq!(
+            parse_to_time_duration("-250ms"),
+            Ok(Duration::milliseconds(-250))
+        );
+        assert_eq!(
+            parse_to_time_duration("18446744073709551615ns"),
+            Ok(Duration::new(18_446_744_073, 709_551_615))
+        );
+
+        for &nanos in &[
+            0_i64,
+            1,
+            -1,
+            61_000_000_000,
+            -3_000_129_723,
+            i64::MAX,
+            i64::MIN + 1,
+        ] {
+            let d = Duration::nanoseconds(nanos);
+            assert_eq!(parse_to_time_duration(&format_time_duration(d)), Ok(d));
+        }
+
+        assert_eq!(parse_to_time_duration(""), Err(ParseError::Empty));
+        assert_eq!(
+            parse_to_time_duration("-"),
+            Err(ParseError::MissingNumber(1))
+        );
+        assert_eq!(
+            parse_to_time_duration("--1s"),
+            Err(ParseError::MissingNumber(1))
+        );
+        assert_eq!(
+            parse_to_time_duration("-1x"),
+            Err(ParseError::UnknownUnit(2))
+        );
+        assert_eq!(
+            parse_to_time_duration("-1ms1s"),
+            Err(ParseError::OutOfOrder(5))
+        );
+        assert_eq!(
+            parse_to_time_duration("18446744073709551616ns"),
+            Err(ParseError::Overflow)
+        );
+    }
+}
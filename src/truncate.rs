@@ -0,0 +1,1333 @@
+use crate::{
+    dhms_parts, parts_ns, to_u64, write_dhms, write_ns, DurationFormatter, Unit, SECOND,
+    SECONDS_PER_DAY, SECONDS_PER_HOUR, SECONDS_PER_MINUTE,
+};
+use alloc::format;
+use alloc::string::String;
+use core::convert::{TryFrom, TryInto};
+use core::fmt::{Debug, Write};
+use core::ops::BitAnd;
+
+/// How the rounding functions treat the discarded part of the duration
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RoundMode {
+    /// Round to the nearest, halfway goes up
+    #[default]
+    HalfUp,
+    /// Round to the nearest, halfway goes to the even multiple (banker's
+    /// rounding), so repeated rounding has no upward bias
+    HalfEven,
+    /// Always round down, drop the discarded part
+    Floor,
+    /// Always round up if anything is discarded
+    Ceil,
+}
+
+/// Round `value` to a multiple of `unit` using `mode`, keeping the floor if
+/// rounding up would overflow
+const fn round(value: u64, unit: u64, mode: RoundMode) -> u64 {
+    let rounded = round_wide(value as u128, unit as u128, mode);
+    if rounded > u64::MAX as u128 {
+        value - value % unit
+    } else {
+        rounded as u64
+    }
+}
+
+/// Same as [`round`] for a `u128`, keeping the floor if rounding up would
+/// overflow
+pub(crate) const fn round_wide(value: u128, unit: u128, mode: RoundMode) -> u128 {
+    let remainder = value % unit;
+    let floor = value - remainder;
+    let up = match mode {
+        RoundMode::HalfUp => remainder >= unit - remainder,
+        RoundMode::HalfEven => {
+            remainder > unit - remainder
+                || (remainder == unit - remainder && (floor / unit) % 2 == 1)
+        }
+        RoundMode::Floor => false,
+        RoundMode::Ceil => remainder != 0,
+    };
+    if up {
+        match floor.checked_add(unit) {
+            Some(rounded) => rounded,
+            None => floor,
+        }
+    } else {
+        floor
+    }
+}
+
+/// Convert seconds to compound duration (days, hours, minutes, seconds) keeping
+/// only the `max_units` most significant non-zero units
+///
+/// The remaining units are discarded, the output is truncated not rounded. A
+/// `max_units` of `0` is treated as `1`, so the largest unit is always shown.
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_truncated;
+///
+/// assert_eq!(format_dhms_truncated(6_000_000, 2), "69d10h");
+/// assert_eq!(format_dhms_truncated(6_000_000, 0), "69d");
+///```
+#[must_use]
+pub fn format_dhms_truncated<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    max_units: usize,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let seconds = to_u64(seconds);
+    if seconds == 0 {
+        return String::from("0s");
+    }
+
+    let mut compound_duration = String::new();
+    for (value, suffix) in dhms_parts(seconds)
+        .iter()
+        .filter(|(value, _)| *value != 0)
+        .take(max_units.max(1))
+    {
+        // writing to a String never fails
+        write!(compound_duration, "{value}{suffix}").unwrap();
+    }
+
+    compound_duration
+}
+
+/// Convert nanoseconds to compound duration (days, hours, minutes, seconds, ms,
+/// µs, ns) keeping only the `n` most significant non-zero units
+///
+/// Like [`format_dhms_truncated`] the remaining units are discarded, the last
+/// unit shown is never rounded up, `1s999ms999µs` with `n = 2` is `"1s999ms"`.
+/// Where the cut happens depends on the value, see [`format_ns_floor`] to
+/// always cut at the same unit. A `n` of `0` is treated as `1`.
+///
+/// Example:
+///```
+/// use compound_duration::format_ns_sig;
+///
+/// assert_eq!(format_ns_sig(100_000_000_010_100_001_u64, 3), "1157d9h46m");
+/// assert_eq!(format_ns_sig(3_000_129_723_u64, 2), "3s129µs");
+///```
+#[must_use]
+pub fn format_ns_sig<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    nanos: T,
+    n: usize,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let mut compound_duration = String::new();
+    for (value, unit) in parts_ns(to_u64(nanos)).iter().take(n.max(1)) {
+        // writing to a String never fails
+        write!(compound_duration, "{value}{}", unit.suffix()).unwrap();
+    }
+
+    compound_duration
+}
+
+/// Convert seconds to compound duration (days, hours, minutes, seconds) keeping
+/// the most significant non-zero units that fit in `max_len` bytes
+///
+/// Units are added from the largest while the output stays within `max_len`,
+/// the first unit that doesn't fit and everything after it is discarded,
+/// truncated not rounded. The largest unit is always shown, even when it is
+/// longer than `max_len` on its own, so the output is never empty.
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_within;
+///
+/// assert_eq!(format_dhms_within(6_000_000, 6), "69d10h");
+/// assert_eq!(format_dhms_within(6_000_000, 9), "69d10h40m");
+/// assert_eq!(format_dhms_within(6_000_000, 1), "69d");
+///```
+#[must_use]
+pub fn format_dhms_within<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    max_len: usize,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let seconds = to_u64(seconds);
+    if seconds == 0 {
+        return String::from("0s");
+    }
+
+    let mut compound_duration = String::new();
+    let mut component = String::new();
+    for (value, suffix) in dhms_parts(seconds).iter().filter(|(value, _)| *value != 0) {
+        component.clear();
+        // writing to a String never fails
+        write!(component, "{value}{suffix}").unwrap();
+        if !compound_duration.is_empty() && compound_duration.len() + component.len() > max_len {
+            break;
+        }
+        compound_duration.push_str(&component);
+    }
+
+    compound_duration
+}
+
+/// Convert seconds to compound duration (days, hours, minutes, seconds) cut to
+/// `max_bytes` with a trailing `"…"` when something is left out
+///
+/// When the full output fits in `max_bytes` it's the same as
+/// [`format_dhms`](crate::format_dhms). Otherwise the most significant units
+/// are kept while they and the ellipsis fit, the ellipsis is three bytes in
+/// UTF-8 and counts against `max_bytes`. Like [`format_dhms_within`] the
+/// largest unit is always shown, so the output can be longer than
+/// `max_bytes` when it doesn't fit on its own. The ellipsis is only added
+/// when a unit is actually left out, so `"69d"` and zero as `"0s"` are never
+/// marked. See [`format_dhms_ellipsized_ascii`] for a `"..."` marker.
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_ellipsized;
+///
+/// assert_eq!(format_dhms_ellipsized(6_000_001, 11), "69d10h40m1s");
+/// assert_eq!(format_dhms_ellipsized(6_000_001, 10), "69d10h…");
+/// assert_eq!(format_dhms_ellipsized(6_000_001, 1), "69d…");
+///```
+#[must_use]
+pub fn format_dhms_ellipsized<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    max_bytes: usize,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    ellipsized(to_u64(seconds), max_bytes, "\u{2026}")
+}
+
+/// Convert seconds to compound duration (days, hours, minutes, seconds) cut to
+/// `max_bytes` with a trailing `"..."` when something is left out
+///
+/// Same as [`format_dhms_ellipsized`] with an ASCII marker for logs that
+/// can't handle Unicode.
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_ellipsized_ascii;
+///
+/// assert_eq!(format_dhms_ellipsized_ascii(6_000_001, 11), "69d10h40m1s");
+/// assert_eq!(format_dhms_ellipsized_ascii(6_000_001, 10), "69d10h...");
+///```
+#[must_use]
+pub fn format_dhms_ellipsized_ascii<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    max_bytes: usize,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    ellipsized(to_u64(seconds), max_bytes, "...")
+}
+
+/// Format `seconds` within `max_bytes`, appending `ellipsis` if any unit is
+/// left out
+fn ellipsized(seconds: u64, max_bytes: usize, ellipsis: &str) -> String {
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_dhms(&mut compound_duration, seconds).unwrap();
+    // zero is a single unit, there is nothing to leave out
+    if seconds == 0 || compound_duration.len() <= max_bytes {
+        return compound_duration;
+    }
+
+    compound_duration.clear();
+    let mut component = String::new();
+    let mut cut = false;
+    for (value, suffix) in dhms_parts(seconds).iter().filter(|(value, _)| *value != 0) {
+        component.clear();
+        write!(component, "{value}{suffix}").unwrap();
+        let len = compound_duration.len() + component.len() + ellipsis.len();
+        if !compound_duration.is_empty() && len > max_bytes {
+            cut = true;
+            break;
+        }
+        compound_duration.push_str(&component);
+    }
+    if cut {
+        compound_duration.push_str(ellipsis);
+    }
+    compound_duration
+}
+
+/// Convert seconds to compound duration (days, hours, minutes, seconds) keeping
+/// only the `max_units` most significant non-zero units, rounding the last one
+///
+/// The last unit shown is rounded half up using the discarded remainder, the
+/// carry propagates to the larger units so `23h59m` rounded to one unit is
+/// `1d`. A `max_units` of `0` is treated as `1`.
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_rounded;
+///
+/// assert_eq!(format_dhms_rounded(6_000_000, 1), "69d");
+/// assert_eq!(format_dhms_rounded(6_000_000, 2), "69d11h");
+/// assert_eq!(format_dhms_rounded(86_340, 1), "1d");
+///```
+#[must_use]
+pub fn format_dhms_rounded<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    max_units: usize,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    format_dhms_rounded_with(seconds, max_units, RoundMode::HalfUp)
+}
+
+/// Convert seconds to compound duration (days, hours, minutes, seconds) keeping
+/// only the `max_units` most significant non-zero units, rounding the last one
+/// with `mode`
+///
+/// Same as [`format_dhms_rounded`] with a choice of [`RoundMode`].
+///
+/// Example:
+///```
+/// use compound_duration::{format_dhms_rounded_with, RoundMode};
+///
+/// // 2h30m rounded to hours
+/// assert_eq!(format_dhms_rounded_with(9000, 1, RoundMode::HalfUp), "3h");
+/// assert_eq!(format_dhms_rounded_with(9000, 1, RoundMode::HalfEven), "2h");
+/// assert_eq!(format_dhms_rounded_with(9000, 1, RoundMode::Floor), "2h");
+/// assert_eq!(format_dhms_rounded_with(9000, 1, RoundMode::Ceil), "3h");
+///```
+#[must_use]
+pub fn format_dhms_rounded_with<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    max_units: usize,
+    mode: RoundMode,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let seconds = to_u64(seconds);
+    let sizes = [
+        SECONDS_PER_DAY as u64,
+        SECONDS_PER_HOUR as u64,
+        SECONDS_PER_MINUTE as u64,
+        SECOND as u64,
+    ];
+
+    // size of the smallest unit that will be shown
+    let unit = dhms_parts(seconds)
+        .iter()
+        .zip(sizes.iter())
+        .filter(|((value, _), _)| *value != 0)
+        .take(max_units.max(1))
+        .last()
+        .map_or(SECOND as u64, |(_, size)| *size);
+
+    // rounding only clears units, so no more than `max_units` remain
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_dhms(&mut compound_duration, round(seconds, unit, mode)).unwrap();
+    compound_duration
+}
+
+/// Convert seconds to compound duration (days, hours, minutes, seconds)
+/// floored to a multiple of `smallest`
+///
+/// Anything below `smallest` is discarded, never rounded up, and durations
+/// shorter than `smallest` are shown as zero of that unit, e.g. `"0m"`. Weeks
+/// are not a dhms unit, so flooring to [`Unit::Week`] leaves a multiple of 7
+/// days.
+///
+/// Example:
+///```
+/// use compound_duration::{format_dhms_floor, Unit};
+///
+/// assert_eq!(format_dhms_floor(59, Unit::Minute), "0m");
+/// assert_eq!(format_dhms_floor(6_000_059, Unit::Minute), "69d10h40m");
+/// assert_eq!(format_dhms_floor(6_000_000, Unit::Day), "69d");
+///```
+#[must_use]
+pub fn format_dhms_floor<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    smallest: Unit,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let seconds = to_u64(seconds);
+    // every number of seconds is a multiple of the units below a second
+    let floored = seconds - seconds % smallest.secs().max(1);
+    if floored == 0 {
+        return format!("0{}", smallest.suffix());
+    }
+
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_dhms(&mut compound_duration, floored).unwrap();
+    compound_duration
+}
+
+/// Convert seconds to compound duration (days, hours, minutes, seconds)
+/// dropping the seconds when hours or days are shown
+///
+/// From one hour (`3600`) up the seconds are discarded, truncated not rounded,
+/// so the output goes down to minutes at most. Below one hour the seconds
+/// reappear and the output is the same as [`format_dhms`](crate::format_dhms).
+///
+/// Example:
+///```
+/// use compound_duration::format_dhms_coarse;
+///
+/// assert_eq!(format_dhms_coarse(6_000_000), "69d10h40m");
+/// assert_eq!(format_dhms_coarse(3661), "1h1m");
+/// assert_eq!(format_dhms_coarse(3599), "59m59s");
+///```
+#[must_use]
+pub fn format_dhms_coarse<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(seconds: T) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let mut seconds = to_u64(seconds);
+    if seconds >= SECONDS_PER_HOUR as u64 {
+        seconds -= seconds % SECONDS_PER_MINUTE as u64;
+    }
+
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_dhms(&mut compound_duration, seconds).unwrap();
+    compound_duration
+}
+
+/// Convert nanoseconds to compound duration (days, hours, minutes, seconds, ms,
+/// µs, ns) floored to a multiple of `smallest`
+///
+/// Like [`format_dhms_floor`] the discarded part is never rounded up, and
+/// durations shorter than `smallest` are shown as zero of that unit, e.g.
+/// `"0ms"`.
+///
+/// Example:
+///```
+/// use compound_duration::{format_ns_floor, Unit};
+///
+/// assert_eq!(
+///     format_ns_floor(100_000_000_010_100_001_u64, Unit::Milli),
+///     "1157d9h46m40s10ms"
+/// );
+/// assert_eq!(format_ns_floor(999_999, Unit::Milli), "0ms");
+///```
+#[must_use]
+pub fn format_ns_floor<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    nanos: T,
+    smallest: Unit,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let nanos = to_u64(nanos);
+    let floored = nanos - nanos % smallest.in_nanos();
+    if floored == 0 {
+        return format!("0{}", smallest.suffix());
+    }
+
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_ns(&mut compound_duration, floored).unwrap();
+    compound_duration
+}
+
+/// Convert nanoseconds to compound duration (days, hours, minutes, seconds, ms,
+/// µs, ns) rounded to a multiple of `smallest`
+///
+/// The discarded part rounds half up and the carry propagates to the larger
+/// units, so `59s999ms` rounded to [`Unit::Second`] is `"1m"`. If rounding up
+/// would overflow a `u64` the value is floored instead. Results below
+/// `smallest` are shown as zero of that unit, e.g. `"0ms"`.
+///
+/// Example:
+///```
+/// use compound_duration::{format_ns_rounded, Unit};
+///
+/// assert_eq!(format_ns_rounded(1_999_999_999, Unit::Second), "2s");
+/// assert_eq!(format_ns_rounded(1_499_999_999, Unit::Second), "1s");
+/// assert_eq!(format_ns_rounded(59_500_000_000_u64, Unit::Second), "1m");
+///```
+#[must_use]
+pub fn format_ns_rounded<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    nanos: T,
+    smallest: Unit,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    format_ns_rounded_with(nanos, smallest, RoundMode::HalfUp)
+}
+
+/// Convert nanoseconds to compound duration (days, hours, minutes, seconds, ms,
+/// µs, ns) rounded to a multiple of `smallest` with `mode`
+///
+/// Same as [`format_ns_rounded`] with a choice of [`RoundMode`].
+///
+/// Example:
+///```
+/// use compound_duration::{format_ns_rounded_with, RoundMode, Unit};
+///
+/// // 2.5ms rounded to milliseconds
+/// assert_eq!(format_ns_rounded_with(2_500_000, Unit::Milli, RoundMode::HalfUp), "3ms");
+/// assert_eq!(format_ns_rounded_with(2_500_000, Unit::Milli, RoundMode::HalfEven), "2ms");
+///```
+#[must_use]
+pub fn format_ns_rounded_with<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    nanos: T,
+    smallest: Unit,
+    mode: RoundMode,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let rounded = round(to_u64(nanos), smallest.in_nanos(), mode);
+    if rounded == 0 {
+        return format!("0{}", smallest.suffix());
+    }
+
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_ns(&mut compound_duration, rounded).unwrap();
+    compound_duration
+}
+
+/// Convert nanoseconds to compound duration (days, hours, minutes, seconds, ms,
+/// µs, ns) rounded down to a multiple of `step_ns`
+///
+/// Like [`format_ns_floor`] with any granularity instead of a [`Unit`], a
+/// `step_ns` of `1_000` floors to microseconds and `250` to quarters of a
+/// microsecond. A result of zero is `"0ns"`.
+///
+/// # Panics
+///
+/// Panics if `step_ns` is zero.
+///
+/// Example:
+///```
+/// use compound_duration::format_ns_quantized;
+///
+/// assert_eq!(format_ns_quantized(1_234_567_u64, 1_000), "1ms234\u{b5}s");
+/// assert_eq!(format_ns_quantized(1_234_567_u64, 250), "1ms234\u{b5}s500ns");
+///```
+#[must_use]
+pub fn format_ns_quantized<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    nanos: T,
+    step_ns: u64,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    format_ns_quantized_with(nanos, step_ns, RoundMode::Floor)
+}
+
+/// Convert nanoseconds to compound duration (days, hours, minutes, seconds, ms,
+/// µs, ns) rounded to a multiple of `step_ns` with `mode`
+///
+/// Same as [`format_ns_quantized`] with a choice of [`RoundMode`].
+///
+/// # Panics
+///
+/// Panics if `step_ns` is zero.
+///
+/// Example:
+///```
+/// use compound_duration::{format_ns_quantized_with, RoundMode};
+///
+/// assert_eq!(format_ns_quantized_with(1_125_u64, 250, RoundMode::Floor), "1\u{b5}s");
+/// assert_eq!(format_ns_quantized_with(1_125_u64, 250, RoundMode::HalfUp), "1\u{b5}s250ns");
+/// assert_eq!(format_ns_quantized_with(1_125_u64, 250, RoundMode::HalfEven), "1\u{b5}s");
+///```
+#[must_use]
+pub fn format_ns_quantized_with<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    nanos: T,
+    step_ns: u64,
+    mode: RoundMode,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    assert!(step_ns != 0, "a step can't be zero nanoseconds long");
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_ns(&mut compound_duration, round(to_u64(nanos), step_ns, mode)).unwrap();
+    compound_duration
+}
+
+/// Default thresholds of [`format_adaptive`] as `(from, smallest)` pairs,
+/// durations of at least one `from` are floored to `smallest`
+///
+/// Every duration is shown down to two units below its largest one, at most
+/// three units in total.
+///
+/// Example:
+///```
+/// use compound_duration::{format_adaptive, format_adaptive_with, Unit, ADAPTIVE_THRESHOLDS};
+///
+/// let nanos = 183_845_000_000_007_u64;
+/// assert_eq!(format_adaptive_with(nanos, &ADAPTIVE_THRESHOLDS), format_adaptive(nanos));
+///
+/// let mut thresholds = ADAPTIVE_THRESHOLDS;
+/// thresholds[0] = (Unit::Day, Unit::Hour);
+/// assert_eq!(format_adaptive_with(nanos, &thresholds), "2d3h");
+///```
+pub const ADAPTIVE_THRESHOLDS: [(Unit, Unit); 6] = [
+    (Unit::Day, Unit::Minute),
+    (Unit::Hour, Unit::Second),
+    (Unit::Minute, Unit::Milli),
+    (Unit::Second, Unit::Micro),
+    (Unit::Milli, Unit::Nano),
+    (Unit::Micro, Unit::Nano),
+];
+
+/// Convert nanoseconds to compound duration (days, hours, minutes, seconds, ms,
+/// µs, ns) with a resolution that depends on the magnitude
+///
+/// Uses [`ADAPTIVE_THRESHOLDS`]: from a day on the output stops at minutes,
+/// from an hour at seconds, from a minute at milliseconds, from a second at
+/// microseconds and below that everything down to nanoseconds is shown. The
+/// discarded units are truncated, not rounded. See [`format_adaptive_with`] to
+/// use other thresholds.
+///
+/// Example:
+///```
+/// use compound_duration::format_adaptive;
+///
+/// assert_eq!(format_adaptive(183_845_000_000_007_u64), "2d3h4m");
+/// assert_eq!(format_adaptive(5_123_u64), "5µs123ns");
+///```
+#[must_use]
+pub fn format_adaptive<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(nanos: T) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    format_adaptive_with(nanos, &ADAPTIVE_THRESHOLDS)
+}
+
+/// Convert nanoseconds to compound duration (days, hours, minutes, seconds, ms,
+/// µs, ns) with a resolution picked from `thresholds`
+///
+/// `thresholds` is a list of `(from, smallest)` pairs, largest `from` first.
+/// The first pair whose `from` unit is no longer than the duration decides the
+/// `smallest` unit shown, the rest is floored like [`format_ns_floor`].
+/// Durations shorter than every `from` are shown in full.
+///
+/// Example:
+///```
+/// use compound_duration::{format_adaptive_with, Unit};
+///
+/// let thresholds = [(Unit::Hour, Unit::Hour), (Unit::Second, Unit::Second)];
+/// assert_eq!(format_adaptive_with(183_845_000_000_007_u64, &thresholds), "2d3h");
+/// assert_eq!(format_adaptive_with(61_500_000_000_u64, &thresholds), "1m1s");
+/// assert_eq!(format_adaptive_with(5_123_u64, &thresholds), "5µs123ns");
+///```
+#[must_use]
+pub fn format_adaptive_with<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    nanos: T,
+    thresholds: &[(Unit, Unit)],
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let nanos = to_u64(nanos);
+    let smallest = thresholds
+        .iter()
+        .find(|(from, _)| nanos >= from.in_nanos())
+        .map_or(Unit::Nano, |&(_, smallest)| smallest);
+    format_ns_floor(nanos, smallest)
+}
+
+/// Convert seconds to compound duration (week, days, hours, minutes, seconds)
+/// never using a unit larger than `largest`
+///
+/// Whatever doesn't fit in `largest` is rolled into it, so capping at
+/// [`Unit::Day`] gives more than 6 days and capping at [`Unit::Hour`] more than
+/// 23 hours. Seconds are the smallest unit, anything below [`Unit::Second`] is
+/// treated as a second.
+///
+/// Example:
+///```
+/// use compound_duration::{format_wdhms_capped, Unit};
+///
+/// assert_eq!(format_wdhms_capped(6_000_000, Unit::Week), "9w6d10h40m");
+/// assert_eq!(format_wdhms_capped(6_000_000, Unit::Day), "69d10h40m");
+/// assert_eq!(format_wdhms_capped(6_000_000, Unit::Hour), "1666h40m");
+///```
+#[must_use]
+pub fn format_wdhms_capped<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    largest: Unit,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    DurationFormatter::new()
+        .with_weeks(largest >= Unit::Week)
+        .with_days(largest >= Unit::Day)
+        .with_hours(largest >= Unit::Hour)
+        .with_minutes(largest >= Unit::Minute)
+        .format(to_u64(seconds))
+}
+
+/// Convert seconds to compound duration (days, hours, minutes, seconds) with a
+/// ceiling of one `cap`
+///
+/// Anything at or above one `cap` is shown as `"1"`, the suffix of `cap` and a
+/// trailing `+`, e.g. `"1h+"` for three hours capped at [`Unit::Hour`], to mark
+/// the value as maxed out. Anything below is the same as
+/// [`format_dhms`](crate::format_dhms). Caps under a second are treated as
+/// [`Unit::Second`], so only zero is shown in full.
+///
+/// Example:
+///```
+/// use compound_duration::{format_capped_plus, Unit};
+///
+/// assert_eq!(format_capped_plus(10_800, Unit::Hour), "1h+");
+/// assert_eq!(format_capped_plus(3600, Unit::Hour), "1h+");
+/// assert_eq!(format_capped_plus(3599, Unit::Hour), "59m59s");
+///```
+#[must_use]
+pub fn format_capped_plus<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    seconds: T,
+    cap: Unit,
+) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let cap = cap.max(Unit::Second);
+    let seconds = to_u64(seconds);
+    if seconds >= cap.secs() {
+        return format!("1{}+", cap.suffix());
+    }
+
+    let mut compound_duration = String::new();
+    // writing to a String never fails
+    write_dhms(&mut compound_duration, seconds).unwrap();
+    compound_duration
+}
+
+/// Convert seconds to an approximate duration showing only the largest unit
+/// (days, hours, minutes)
+///
+/// The unit is rounded half up using the rest of the duration, so `69d10h40m`
+/// is `"69d"` and `1d12h` is `"2d"`, rounding can carry into the next unit,
+/// `23h59m` is `"1d"`. Anything under a minute is `"<1m"`.
+///
+/// Example:
+///```
+/// use compound_duration::format_approx;
+///
+/// assert_eq!(format_approx(6_000_000), "69d");
+/// assert_eq!(format_approx(10_800), "3h");
+/// assert_eq!(format_approx(59), "<1m");
+///```
+#[must_use]
+pub fn format_approx<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(seconds: T) -> String
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let seconds = to_u64(seconds);
+    if seconds < SECONDS_PER_MINUTE as u64 {
+        return String::from("<1m");
+    }
+    format_dhms_rounded(seconds, 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        format_adaptive, format_adaptive_with, format_approx, format_capped_plus,
+        format_dhms_coarse, format_dhms_ellipsized, format_dhms_ellipsized_ascii,
+        format_dhms_floor, format_dhms_rounded, format_dhms_rounded_with, format_dhms_truncated,
+        format_dhms_within, format_ns_floor, format_ns_quantized, format_ns_quantized_with,
+        format_ns_rounded, format_ns_rounded_with, format_ns_sig, format_wdhms_capped, RoundMode,
+    };
+    use crate::{format_dhms, format_ns, format_wdhms, Unit};
+
+    #[test]
+    fn test_format_dhms_truncated() {
+        assert_eq!(format_dhms_truncated(0, 2), "0s");
+        assert_eq!(format_dhms_truncated(0, 0), "0s");
+        assert_eq!(format_dhms_truncated(6_000_000, 0), "69d");
+        assert_eq!(format_dhms_truncated(6_000_000, 1), "69d");
+        assert_eq!(format_dhms_truncated(6_000_000, 2), "69d10h");
+        assert_eq!(format_dhms_truncated(6_000_000, 3), "69d10h40m");
+        assert_eq!(format_dhms_truncated(6_000_000, 4), "69d10h40m");
+        assert_eq!(format_dhms_truncated(6_000_000, usize::MAX), "69d10h40m");
+        assert_eq!(format_dhms_truncated(86401, 2), "1d1s");
+        assert_eq!(format_dhms_truncated(7259, 1), "2h");
+        // never rounded up
+        assert_eq!(format_dhms_truncated(86399, 1), "23h");
+        for seconds in (0..1_000_000).step_by(997) {
+            assert_eq!(format_dhms_truncated(seconds, 4), format_dhms(seconds));
+        }
+    }
+
+    #[test]
+    fn test_format_dhms_rounded() {
+        assert_eq!(format_dhms_rounded(0, 1), "0s");
+        assert_eq!(format_dhms_rounded(59, 1), "59s");
+        assert_eq!(format_dhms_rounded(6_000_000, 0), "69d");
+        assert_eq!(format_dhms_rounded(6_000_000, 1), "69d");
+        assert_eq!(format_dhms_rounded(6_000_000, 2), "69d11h");
+        assert_eq!(format_dhms_rounded(6_000_000, 3), "69d10h40m");
+        assert_eq!(format_dhms_rounded(89, 1), "1m");
+        assert_eq!(format_dhms_rounded(90, 1), "2m");
+        assert_eq!(format_dhms_rounded(5399, 1), "1h");
+        assert_eq!(format_dhms_rounded(5400, 1), "2h");
+        // skipped zero units are not shown
+        assert_eq!(format_dhms_rounded(86_401, 2), "1d1s");
+
+        // carry cascades across units
+        assert_eq!(format_dhms_rounded(86_340, 1), "1d");
+        assert_eq!(format_dhms_rounded(86_370, 2), "1d");
+        assert_eq!(format_dhms_rounded(172_799, 3), "2d");
+        assert_eq!(format_dhms_rounded(3599, 1), "1h");
+        assert_eq!(format_dhms_rounded(43_200, 1), "12h");
+        assert_eq!(format_dhms_rounded(129_600, 1), "2d");
+
+        // does not overflow
+        assert_eq!(format_dhms_rounded(u64::MAX, 2), "213503982334601d7h");
+        assert_eq!(format_dhms_rounded(u64::MAX, 4), "213503982334601d7h15s");
+    }
+
+    #[test]
+    fn test_format_dhms_floor() {
+        assert_eq!(format_dhms_floor(0, Unit::Second), "0s");
+        assert_eq!(format_dhms_floor(0, Unit::Minute), "0m");
+        assert_eq!(format_dhms_floor(59, Unit::Minute), "0m");
+        assert_eq!(format_dhms_floor(60, Unit::Minute), "1m");
+        assert_eq!(format_dhms_floor(3599, Unit::Hour), "0h");
+        assert_eq!(format_dhms_floor(86_399, Unit::Day), "0d");
+        assert_eq!(format_dhms_floor(604_799, Unit::Week), "0w");
+        assert_eq!(format_dhms_floor(6_000_000, Unit::Second), "69d10h40m");
+        assert_eq!(format_dhms_floor(6_000_059, Unit::Minute), "69d10h40m");
+        assert_eq!(format_dhms_floor(6_000_000, Unit::Hour), "69d10h");
+        assert_eq!(format_dhms_floor(6_000_000, Unit::Day), "69d");
+        assert_eq!(format_dhms_floor(6_000_000, Unit::Week), "63d");
+        assert_eq!(format_dhms_floor(6_000_001, Unit::Milli), "69d10h40m1s");
+        assert_eq!(format_dhms_floor(0, Unit::Nano), "0ns");
+        // never rounded up
+        assert_eq!(format_dhms_floor(86_399, Unit::Hour), "23h");
+        assert_eq!(format_dhms_floor(86_401, Unit::Minute), "1d");
+        for seconds in (0..1_000_000).step_by(997) {
+            assert_eq!(
+                format_dhms_floor(seconds, Unit::Second),
+                format_dhms(seconds)
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_approx() {
+        assert_eq!(format_approx(0), "<1m");
+        assert_eq!(format_approx(59), "<1m");
+        assert_eq!(format_approx(60), "1m");
+        assert_eq!(format_approx(89), "1m");
+        assert_eq!(format_approx(90), "2m");
+        assert_eq!(format_approx(3570), "1h");
+        assert_eq!(format_approx(10_800), "3h");
+        assert_eq!(format_approx(12_599), "3h");
+        assert_eq!(format_approx(12_600), "4h");
+        assert_eq!(format_approx(86_340), "1d");
+        assert_eq!(format_approx(129_600), "2d");
+        assert_eq!(format_approx(6_000_000), "69d");
+        assert_eq!(format_approx(4_294_967_295_u32), "49710d");
+    }
+
+    #[test]
+    fn test_format_wdhms_capped() {
+        assert_eq!(format_wdhms_capped(0, Unit::Week), "0s");
+        assert_eq!(format_wdhms_capped(0, Unit::Hour), "0s");
+        assert_eq!(
+            format_wdhms_capped(6_000_000, Unit::Week),
+            format_wdhms(6_000_000)
+        );
+        assert_eq!(
+            format_wdhms_capped(6_000_000, Unit::Day),
+            format_dhms(6_000_000)
+        );
+        assert_eq!(format_wdhms_capped(6_000_000, Unit::Day), "69d10h40m");
+        assert_eq!(format_wdhms_capped(6_000_000, Unit::Minute), "100000m");
+        assert_eq!(format_wdhms_capped(6_000_000, Unit::Second), "6000000s");
+        assert_eq!(format_wdhms_capped(6_000_000, Unit::Nano), "6000000s");
+
+        // hours can exceed 23
+        assert_eq!(format_wdhms_capped(86_400, Unit::Hour), "24h");
+        assert_eq!(format_wdhms_capped(90_061, Unit::Hour), "25h1m1s");
+        assert_eq!(format_wdhms_capped(6_000_000, Unit::Hour), "1666h40m");
+        assert_eq!(format_wdhms_capped(3599, Unit::Hour), "59m59s");
+        assert_eq!(
+            format_wdhms_capped(4_294_967_295_u32, Unit::Hour),
+            "1193046h28m15s"
+        );
+    }
+
+    #[test]
+    fn test_format_ns_floor() {
+        let nanos = 100_000_000_010_100_001_u64;
+        assert_eq!(format_ns_floor(nanos, Unit::Nano), format_ns(nanos));
+        assert_eq!(
+            format_ns_floor(nanos, Unit::Micro),
+            "1157d9h46m40s10ms100\u{b5}s"
+        );
+        assert_eq!(format_ns_floor(nanos, Unit::Milli), "1157d9h46m40s10ms");
+        assert_eq!(format_ns_floor(nanos, Unit::Second), "1157d9h46m40s");
+        assert_eq!(format_ns_floor(nanos, Unit::Minute), "1157d9h46m");
+        assert_eq!(format_ns_floor(nanos, Unit::Week), "1155d");
+
+        // never rounded up
+        assert_eq!(format_ns_floor(1_999_999_999, Unit::Second), "1s");
+        assert_eq!(format_ns_floor(1_999_999, Unit::Milli), "1ms");
+
+        assert_eq!(format_ns_floor(0, Unit::Nano), "0ns");
+        assert_eq!(format_ns_floor(0, Unit::Milli), "0ms");
+        assert_eq!(format_ns_floor(999, Unit::Micro), "0\u{b5}s");
+        assert_eq!(format_ns_floor(59_999_999_999_u64, Unit::Minute), "0m");
+    }
+
+    #[test]
+    fn test_format_ns_rounded() {
+        let nanos = 100_000_000_010_100_001_u64;
+        assert_eq!(format_ns_rounded(nanos, Unit::Nano), format_ns(nanos));
+        assert_eq!(
+            format_ns_rounded(nanos, Unit::Micro),
+            "1157d9h46m40s10ms100\u{b5}s"
+        );
+        assert_eq!(format_ns_rounded(nanos, Unit::Milli), "1157d9h46m40s10ms");
+        assert_eq!(format_ns_rounded(nanos, Unit::Second), "1157d9h46m40s");
+        assert_eq!(format_ns_rounded(nanos, Unit::Minute), "1157d9h47m");
+        assert_eq!(format_ns_rounded(nanos, Unit::Week), "1155d");
+
+        assert_eq!(format_ns_rounded(1_999_999_999, Unit::Second), "2s");
+        assert_eq!(format_ns_rounded(1_500_000_000, Unit::Second), "2s");
+        assert_eq!(format_ns_rounded(1_499_999_999, Unit::Second), "1s");
+        assert_eq!(format_ns_rounded(1_999_499, Unit::Milli), "2ms");
+        assert_eq!(format_ns_rounded(1_499, Unit::Micro), "1\u{b5}s");
+
+        // the carry cascades up through the larger units
+        assert_eq!(format_ns_rounded(59_999_000_000_u64, Unit::Second), "1m");
+        assert_eq!(
+            format_ns_rounded(59_999_000_000_u64, Unit::Milli),
+            "59s999ms"
+        );
+        assert_eq!(format_ns_rounded(3_599_500_000_000_u64, Unit::Second), "1h");
+        assert_eq!(format_ns_rounded(86_399_999_999_999_u64, Unit::Milli), "1d");
+
+        assert_eq!(format_ns_rounded(0, Unit::Second), "0s");
+        assert_eq!(format_ns_rounded(499, Unit::Micro), "0\u{b5}s");
+        assert_eq!(format_ns_rounded(500, Unit::Micro), "1\u{b5}s");
+        assert_eq!(format_ns_rounded(u64::MAX, Unit::Day), "213503d");
+    }
+
+    #[test]
+    fn test_format_dhms_rounded_with() {
+        use RoundMode::{Ceil, Floor, HalfEven, HalfUp};
+
+        // exactly half an hour over
+        assert_eq!(format_dhms_rounded_with(1800, 1, HalfUp), "30m");
+        assert_eq!(format_dhms_rounded_with(5400, 1, HalfUp), "2h");
+        assert_eq!(format_dhms_rounded_with(5400, 1, HalfEven), "2h");
+        assert_eq!(format_dhms_rounded_with(5400, 1, Floor), "1h");
+        assert_eq!(format_dhms_rounded_with(5400, 1, Ceil), "2h");
+        assert_eq!(format_dhms_rounded_with(9000, 1, HalfUp), "3h");
+        assert_eq!(format_dhms_rounded_with(9000, 1, HalfEven), "2h");
+        assert_eq!(format_dhms_rounded_with(9000, 1, Floor), "2h");
+        assert_eq!(format_dhms_rounded_with(9000, 1, Ceil), "3h");
+
+        // just under and over half
+        assert_eq!(format_dhms_rounded_with(8999, 1, HalfEven), "2h");
+        assert_eq!(format_dhms_rounded_with(9001, 1, HalfEven), "3h");
+        assert_eq!(format_dhms_rounded_with(7201, 1, Floor), "2h");
+        assert_eq!(format_dhms_rounded_with(7201, 1, Ceil), "3h");
+        assert_eq!(format_dhms_rounded_with(7200, 1, Ceil), "2h");
+
+        // carry
+        assert_eq!(format_dhms_rounded_with(86_399, 1, Ceil), "1d");
+        assert_eq!(format_dhms_rounded_with(86_399, 1, Floor), "23h");
+        assert_eq!(format_dhms_rounded_with(84_600, 1, HalfEven), "1d");
+
+        for seconds in (0..10_000_000).step_by(9973) {
+            for max_units in 0..5 {
+                assert_eq!(
+                    format_dhms_rounded_with(seconds, max_units, HalfUp),
+                    format_dhms_rounded(seconds, max_units)
+                );
+                assert_eq!(
+                    format_dhms_rounded_with(seconds, max_units, Floor),
+                    format_dhms_truncated(seconds, max_units)
+                );
+            }
+        }
+        assert_eq!(
+            format_dhms_rounded_with(u64::MAX, 1, Ceil),
+            "213503982334601d"
+        );
+    }
+
+    #[test]
+    fn test_format_ns_rounded_with() {
+        use RoundMode::{Ceil, Floor, HalfEven, HalfUp};
+
+        assert_eq!(
+            format_ns_rounded_with(2_500_000, Unit::Milli, HalfUp),
+            "3ms"
+        );
+        assert_eq!(
+            format_ns_rounded_with(2_500_000, Unit::Milli, HalfEven),
+            "2ms"
+        );
+        assert_eq!(
+            format_ns_rounded_with(3_500_000, Unit::Milli, HalfEven),
+            "4ms"
+        );
+        assert_eq!(format_ns_rounded_with(2_500_000, Unit::Milli, Floor), "2ms");
+        assert_eq!(format_ns_rounded_with(2_500_000, Unit::Milli, Ceil), "3ms");
+        assert_eq!(format_ns_rounded_with(2_000_001, Unit::Milli, Ceil), "3ms");
+        assert_eq!(format_ns_rounded_with(1, Unit::Second, Ceil), "1s");
+        assert_eq!(format_ns_rounded_with(1, Unit::Second, HalfEven), "0s");
+        assert_eq!(
+            format_ns_rounded_with(500_000_000, Unit::Second, HalfEven),
+            "0s"
+        );
+        assert_eq!(
+            format_ns_rounded_with(1_500_000_000, Unit::Second, HalfEven),
+            "2s"
+        );
+        assert_eq!(
+            format_ns_rounded_with(59_000_000_001_u64, Unit::Second, Ceil),
+            "1m"
+        );
+
+        let nanos = 100_000_000_010_100_001_u64;
+        for &unit in &Unit::ALL {
+            assert_eq!(
+                format_ns_rounded_with(nanos, unit, HalfUp),
+                format_ns_rounded(nanos, unit)
+            );
+            assert_eq!(
+                format_ns_rounded_with(nanos, unit, Floor),
+                format_ns_floor(nanos, unit)
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_ns_quantized() {
+        assert_eq!(format_ns_quantized(0, 1), "0ns");
+        assert_eq!(format_ns_quantized(0, 1_000), "0ns");
+        assert_eq!(format_ns_quantized(999, 1_000), "0ns");
+        assert_eq!(format_ns_quantized(499, 500), "0ns");
+        assert_eq!(format_ns_quantized(500, 500), "500ns");
+        assert_eq!(format_ns_quantized(1_499, 500), "1\u{b5}s");
+        assert_eq!(format_ns_quantized(1_234_567_u64, 1_000), "1ms234\u{b5}s");
+        assert_eq!(
+            format_ns_quantized(1_234_567_u64, 250),
+            "1ms234\u{b5}s500ns"
+        );
+        assert_eq!(format_ns_quantized(1_234_567_u64, 7), "1ms234\u{b5}s562ns");
+        assert_eq!(format_ns_quantized(u64::MAX, 1), format_ns(u64::MAX));
+        assert_eq!(format_ns_quantized(u64::MAX, u64::MAX), format_ns(u64::MAX));
+
+        // the fixed unit boundaries are a special case
+        let nanos = 100_000_000_010_100_001_u64;
+        for &unit in &Unit::ALL {
+            assert_eq!(
+                format_ns_quantized(nanos, unit.in_nanos()),
+                format_ns_floor(nanos, unit)
+            );
+        }
+        for nanos in (0..1_000_000).step_by(997) {
+            assert_eq!(format_ns_quantized(nanos, 1), format_ns(nanos));
+        }
+    }
+
+    #[test]
+    fn test_format_ns_quantized_with() {
+        use RoundMode::{Ceil, Floor, HalfEven, HalfUp};
+
+        assert_eq!(format_ns_quantized_with(1_375, 250, Floor), "1\u{b5}s250ns");
+        assert_eq!(
+            format_ns_quantized_with(1_375, 250, HalfUp),
+            "1\u{b5}s500ns"
+        );
+        assert_eq!(
+            format_ns_quantized_with(1_375, 250, HalfEven),
+            "1\u{b5}s500ns"
+        );
+        assert_eq!(format_ns_quantized_with(1_125, 250, HalfEven), "1\u{b5}s");
+        assert_eq!(format_ns_quantized_with(1_251, 250, Ceil), "1\u{b5}s500ns");
+        assert_eq!(format_ns_quantized_with(1_250, 250, Ceil), "1\u{b5}s250ns");
+        assert_eq!(format_ns_quantized_with(1, 250, Ceil), "250ns");
+        assert_eq!(format_ns_quantized_with(124, 250, HalfUp), "0ns");
+
+        // rounding up past u64::MAX keeps the floor
+        assert_eq!(
+            format_ns_quantized_with(u64::MAX, 1_000, Ceil),
+            format_ns_quantized(u64::MAX, 1_000)
+        );
+
+        for nanos in (1_000..1_000_000).step_by(997) {
+            assert_eq!(
+                format_ns_quantized_with(nanos, 1_000, HalfUp),
+                format_ns_rounded(nanos, Unit::Micro)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "a step can't be zero nanoseconds long")]
+    fn test_format_ns_quantized_zero_step() {
+        let _ = format_ns_quantized(1_000, 0);
+    }
+
+    #[test]
+    fn test_format_ns_sig() {
+        let nanos = 100_000_000_010_100_001_u64;
+        assert_eq!(format_ns_sig(nanos, 0), "1157d");
+        assert_eq!(format_ns_sig(nanos, 1), "1157d");
+        assert_eq!(format_ns_sig(nanos, 3), "1157d9h46m");
+        assert_eq!(format_ns_sig(nanos, 6), "1157d9h46m40s10ms100\u{b5}s");
+        assert_eq!(format_ns_sig(nanos, 7), format_ns(nanos));
+        assert_eq!(format_ns_sig(nanos, 100), format_ns(nanos));
+
+        // the cut adapts to the value
+        assert_eq!(format_ns_sig(3_000_129_723_u64, 2), "3s129\u{b5}s");
+        assert_eq!(format_ns_sig(129_723, 2), "129\u{b5}s723ns");
+
+        // truncated, not rounded
+        assert_eq!(format_ns_sig(1_999_999_999, 1), "1s");
+        assert_eq!(format_ns_sig(1_999_999_999, 2), "1s999ms");
+
+        assert_eq!(format_ns_sig(0, 0), "0ns");
+        assert_eq!(format_ns_sig(0, 3), "0ns");
+        assert_eq!(format_ns_sig(1, 3), "1ns");
+    }
+
+    #[test]
+    fn test_format_adaptive() {
+        assert_eq!(format_adaptive(0), "0ns");
+        assert_eq!(format_adaptive(1), "1ns");
+        assert_eq!(format_adaptive(5_123), "5\u{b5}s123ns");
+        assert_eq!(format_adaptive(5_123_456), "5ms123\u{b5}s456ns");
+        assert_eq!(format_adaptive(3_000_129_723_u64), "3s129\u{b5}s");
+        assert_eq!(format_adaptive(61_500_000_001_u64), "1m1s500ms");
+        assert_eq!(format_adaptive(3_723_000_000_001_u64), "1h2m3s");
+        assert_eq!(format_adaptive(183_845_000_000_007_u64), "2d3h4m");
+        assert_eq!(format_adaptive(100_000_000_010_100_001_u64), "1157d9h46m");
+
+        // floored to the resolution, not rounded
+        assert_eq!(format_adaptive(3_659_999_999_999_u64), "1h59s");
+        assert_eq!(
+            format_adaptive(86_400_000_000_000_u64 + 59_999_999_999),
+            "1d"
+        );
+    }
+
+    #[test]
+    fn test_format_adaptive_with() {
+        let nanos = 100_000_000_010_100_001_u64;
+        assert_eq!(format_adaptive_with(nanos, &[]), format_ns(nanos));
+        assert_eq!(
+            format_adaptive_with(nanos, &[(Unit::Nano, Unit::Day)]),
+            "1157d"
+        );
+        assert_eq!(
+            format_adaptive_with(
+                nanos,
+                &[(Unit::Week, Unit::Hour), (Unit::Day, Unit::Second)]
+            ),
+            "1157d9h"
+        );
+
+        let thresholds = [(Unit::Hour, Unit::Hour), (Unit::Second, Unit::Second)];
+        assert_eq!(
+            format_adaptive_with(183_845_000_000_007_u64, &thresholds),
+            "2d3h"
+        );
+        assert_eq!(
+            format_adaptive_with(3_599_999_999_999_u64, &thresholds),
+            "59m59s"
+        );
+        assert_eq!(
+            format_adaptive_with(999_999_999, &thresholds),
+            "999ms999\u{b5}s999ns"
+        );
+    }
+
+    #[test]
+    fn test_format_dhms_within() {
+        assert_eq!(format_dhms_within(0, 0), "0s");
+        assert_eq!(format_dhms_within(0, 10), "0s");
+        assert_eq!(format_dhms_within(61, 4), "1m1s");
+        assert_eq!(format_dhms_within(61, 3), "1m");
+        assert_eq!(format_dhms_within(6_000_000, 100), "69d10h40m");
+        assert_eq!(format_dhms_within(6_000_000, 9), "69d10h40m");
+        assert_eq!(format_dhms_within(6_000_000, 8), "69d10h");
+        assert_eq!(format_dhms_within(6_000_000, 6), "69d10h");
+        assert_eq!(format_dhms_within(6_000_000, 5), "69d");
+        assert_eq!(format_dhms_within(6_000_000, 3), "69d");
+
+        // the largest unit is kept even if it is too long
+        assert_eq!(format_dhms_within(6_000_000, 0), "69d");
+        assert_eq!(format_dhms_within(4_294_967_295_u32, 3), "49710d");
+
+        // a unit that doesn't fit ends the output, smaller ones are not tried
+        assert_eq!(format_dhms_within(86_400 * 10 + 36_001, 5), "10d");
+        assert_eq!(format_dhms_within(86_400 * 10 + 36_001, 6), "10d10h");
+
+        for seconds in (0..10_000_000).step_by(9973) {
+            for max_len in 0..12 {
+                let within = format_dhms_within(seconds, max_len);
+                assert!(format_dhms(seconds).starts_with(&within));
+                assert!(within.len() <= max_len || within == format_dhms_truncated(seconds, 1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_format_dhms_coarse() {
+        assert_eq!(format_dhms_coarse(0), "0s");
+        assert_eq!(format_dhms_coarse(59), "59s");
+        assert_eq!(format_dhms_coarse(61), "1m1s");
+        assert_eq!(format_dhms_coarse(3599), "59m59s");
+        // seconds are dropped from one hour up
+        assert_eq!(format_dhms_coarse(3600), "1h");
+        assert_eq!(format_dhms_coarse(3601), "1h");
+        assert_eq!(format_dhms_coarse(3659), "1h");
+        assert_eq!(format_dhms_coarse(3661), "1h1m");
+        assert_eq!(format_dhms_coarse(86_519), "1d1m");
+        assert_eq!(format_dhms_coarse(86_401), "1d");
+        assert_eq!(format_dhms_coarse(6_000_000), "69d10h40m");
+        assert_eq!(format_dhms_coarse(6_000_059), "69d10h40m");
+        assert_eq!(format_dhms_coarse(u64::MAX), "213503982334601d7h");
+        for seconds in 0..3600 {
+            assert_eq!(format_dhms_coarse(seconds), format_dhms(seconds));
+        }
+        for seconds in (3600..1_000_000).step_by(997) {
+            assert!(!format_dhms_coarse(seconds).ends_with('s'));
+        }
+    }
+
+    #[test]
+    fn test_format_capped_plus() {
+        assert_eq!(format_capped_plus(0, Unit::Hour), "0s");
+        assert_eq!(format_capped_plus(3599, Unit::Hour), "59m59s");
+        // at and above the cap
+        assert_eq!(format_capped_plus(3600, Unit::Hour), "1h+");
+        assert_eq!(format_capped_plus(10_800, Unit::Hour), "1h+");
+        assert_eq!(format_capped_plus(u64::MAX, Unit::Hour), "1h+");
+        assert_eq!(format_capped_plus(59, Unit::Minute), "59s");
+        assert_eq!(format_capped_plus(60, Unit::Minute), "1m+");
+        assert_eq!(format_capped_plus(6_000_000, Unit::Week), "1w+");
+        assert_eq!(format_capped_plus(604_799, Unit::Week), "6d23h59m59s");
+        assert_eq!(format_capped_plus(86_400, Unit::Day), "1d+");
+        // under a second is a second
+        assert_eq!(format_capped_plus(0, Unit::Second), "0s");
+        assert_eq!(format_capped_plus(1, Unit::Second), "1s+");
+        assert_eq!(format_capped_plus(0, Unit::Nano), "0s");
+        assert_eq!(format_capped_plus(1, Unit::Milli), "1s+");
+
+        for seconds in (0..604_800).step_by(997) {
+            assert_eq!(
+                format_capped_plus(seconds, Unit::Week),
+                format_dhms(seconds)
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_dhms_ellipsized() {
+        // fits, nothing is cut
+        assert_eq!(format_dhms_ellipsized(0, 0), "0s");
+        assert_eq!(format_dhms_ellipsized(0, 2), "0s");
+        assert_eq!(format_dhms_ellipsized(6_000_000, 9), "69d10h40m");
+        assert_eq!(format_dhms_ellipsized(6_000_000, 100), "69d10h40m");
+
+        // the ellipsis is three bytes
+        assert_eq!("\u{2026}".len(), 3);
+        assert_eq!(format_dhms_ellipsized(6_000_000, 8), "69d\u{2026}");
+        assert_eq!(format_dhms_ellipsized(6_000_001, 11), "69d10h40m1s");
+        assert_eq!(format_dhms_ellipsized(6_000_001, 10), "69d10h\u{2026}");
+        assert_eq!(format_dhms_ellipsized(6_000_001, 9), "69d10h\u{2026}");
+        assert_eq!(format_dhms_ellipsized(6_000_000, 7), "69d\u{2026}");
+        assert_eq!(format_dhms_ellipsized(6_000_000, 6), "69d\u{2026}");
+        // the largest unit is always shown
+        assert_eq!(format_dhms_ellipsized(6_000_000, 1), "69d\u{2026}");
+        assert_eq!(format_dhms_ellipsized(6_000_000, 0), "69d\u{2026}");
+        assert_eq!(
+            format_dhms_ellipsized(u64::MAX, 21),
+            "213503982334601d7h15s"
+        );
+        assert_eq!(
+            format_dhms_ellipsized(u64::MAX, 20),
+            "213503982334601d\u{2026}"
+        );
+
+        // a single unit is complete, nothing is left out
+        assert_eq!(format_dhms_ellipsized(60, 1), "1m");
+        assert_eq!(format_dhms_ellipsized(86_400 * 69, 1), "69d");
+        assert_eq!(format_dhms_ellipsized(5_961_600, 0), "69d");
+
+        for seconds in (1..10_000_000).step_by(9973) {
+            let full = format_dhms(seconds);
+            for max_bytes in 0..=full.len() + 1 {
+                let cut = format_dhms_ellipsized(seconds, max_bytes);
+                if max_bytes >= full.len() {
+                    assert_eq!(cut, full);
+                    continue;
+                }
+                let Some(kept) = cut.strip_suffix('\u{2026}') else {
+                    // a single unit has nothing to leave out
+                    assert_eq!(cut, full);
+                    assert_eq!(full, format_dhms_truncated(seconds, 1));
+                    continue;
+                };
+                assert!(full.starts_with(kept));
+                assert_ne!(kept, full);
+                // only the largest unit can go over
+                assert!(cut.len() <= max_bytes || kept == format_dhms_truncated(seconds, 1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_format_dhms_ellipsized_ascii() {
+        assert_eq!(format_dhms_ellipsized_ascii(0, 0), "0s");
+        assert_eq!(format_dhms_ellipsized_ascii(6_000_000, 9), "69d10h40m");
+        assert_eq!(format_dhms_ellipsized_ascii(6_000_000, 8), "69d...");
+        assert_eq!(format_dhms_ellipsized_ascii(6_000_001, 10), "69d10h...");
+        assert_eq!(format_dhms_ellipsized_ascii(6_000_000, 6), "69d...");
+        assert_eq!(format_dhms_ellipsized_ascii(6_000_000, 1), "69d...");
+        assert_eq!(format_dhms_ellipsized_ascii(86_401, 4), "1d1s");
+        assert_eq!(format_dhms_ellipsized_ascii(86_401, 3), "1d...");
+        assert_eq!(format_dhms_ellipsized_ascii(60, 1), "1m");
+        assert_eq!(format_dhms_ellipsized_ascii(86_400 * 69, 2), "69d");
+        assert!(format_dhms_ellipsized_ascii(u64::MAX, 10).is_ascii());
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{
+    NANOS_PER_MS, NANOS_PER_SECOND, NANOS_PER_US, SECOND, SECONDS_PER_DAY, SECONDS_PER_HOUR,
+    SECONDS_PER_MINUTE, SECONDS_PER_WEEK,
+};
+
+/// A unit of time used by the compound duration functions
+///
+/// Variants are ordered from the smallest to the largest unit.
+///
+/// Example:
+///```
+/// use compound_duration::Unit;
+///
+/// assert_eq!(Unit::Hour.in_nanos(), 3_600_000_000_000);
+/// assert_eq!(Unit::Micro.suffix(), "\u{b5}s");
+/// assert!(Unit::Milli < Unit::Second);
+///```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Unit {
+    Nano,
+    Micro,
+    Milli,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+}
+
+impl Unit {
+    /// All the units, from the largest to the smallest
+    pub const ALL: [Self; 8] = [
+        Self::Week,
+        Self::Day,
+        Self::Hour,
+        Self::Minute,
+        Self::Second,
+        Self::Milli,
+        Self::Micro,
+        Self::Nano,
+    ];
+
+    /// Length of the unit in nanoseconds
+    #[must_use]
+    pub const fn in_nanos(self) -> u64 {
+        match self {
+            Self::Nano => 1,
+            Self::Micro => NANOS_PER_US as u64,
+            Self::Milli => NANOS_PER_MS as u64,
+            Self::Second => SECOND as u64 * NANOS_PER_SECOND as u64,
+            Self::Minute => SECONDS_PER_MINUTE as u64 * NANOS_PER_SECOND as u64,
+            Self::Hour => SECONDS_PER_HOUR as u64 * NANOS_PER_SECOND as u64,
+            Self::Day => SECONDS_PER_DAY as u64 * NANOS_PER_SECOND as u64,
+            Self::Week => SECONDS_PER_WEEK as u64 * NANOS_PER_SECOND as u64,
+        }
+    }
+
+    /// Suffix used when formatting the unit
+    #[must_use]
+    pub const fn suffix(self) -> &'static str {
+        match self {
+            Self::Nano => "ns",
+            Self::Micro => "\u{b5}s",
+            Self::Milli => "ms",
+            Self::Second => "s",
+            Self::Minute => "m",
+            Self::Hour => "h",
+            Self::Day => "d",
+            Self::Week => "w",
+        }
+    }
+
+    /// Length of the unit in whole seconds, zero for units under a second
+    pub(crate) const fn secs(self) -> u64 {
+        self.in_nanos() / NANOS_PER_SECOND as u64
+    }
+}
+
+/// Total number of whole `unit`s in `seconds`
+///
+/// The count is floored, `119` seconds are `1` minute. Units under a second
+/// multiply instead and saturate at `u64::MAX`.
+///
+/// Example:
+///```
+/// use compound_duration::{total_in, Unit};
+///
+/// assert_eq!(total_in(6_000_000, Unit::Minute), 100_000);
+/// assert_eq!(total_in(6_000_000, Unit::Day), 69);
+/// assert_eq!(total_in(2, Unit::Milli), 2000);
+///```
+#[must_use]
+pub const fn total_in(seconds: u64, unit: Unit) -> u64 {
+    match seconds.checked_div(unit.secs()) {
+        Some(total) => total,
+        // under a second
+        None => seconds.saturating_mul(NANOS_PER_SECOND as u64 / unit.in_nanos()),
+    }
+}
+
+/// Largest non-zero unit of `seconds`, from [`Unit::Week`] down to
+/// [`Unit::Second`]
+///
+/// Useful to bucket durations by scale without formatting them. Zero is
+/// [`Unit::Second`], the same unit `"0s"` is written in.
+///
+/// Example:
+///```
+/// use compound_duration::{dominant_unit, Unit};
+///
+/// assert_eq!(dominant_unit(1_209_600), Unit::Week);
+/// assert_eq!(dominant_unit(3599), Unit::Minute);
+/// assert_eq!(dominant_unit(5), Unit::Second);
+/// assert_eq!(dominant_unit(0), Unit::Second);
+///```
+#[must_use]
+pub const fn dominant_unit(seconds: u64) -> Unit {
+    let mut i = 0;
+    while i < Unit::ALL.len() {
+        let unit = Unit::ALL[i];
+        if unit.secs() != 0 && seconds >= unit.secs() {
+            return unit;
+        }
+        i += 1;
+    }
+    Unit::Second
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dominant_unit, total_in, Unit};
+    use crate::{
+        NANOS_PER_MS, NANOS_PER_SECOND, NANOS_PER_US, SECOND, SECONDS_PER_DAY, SECONDS_PER_HOUR,
+        SECONDS_PER_MINUTE, SECONDS_PER_WEEK,
+    };
+
+    #[test]
+    fn test_unit() {
+        assert_eq!(Unit::Nano.in_nanos(), 1);
+        assert_eq!(Unit::Micro.in_nanos(), NANOS_PER_US as u64);
+        assert_eq!(Unit::Milli.in_nanos(), NANOS_PER_MS as u64);
+        assert_eq!(Unit::Second.in_nanos(), NANOS_PER_SECOND as u64);
+        assert_eq!(Unit::Second.secs(), SECOND as u64);
+        assert_eq!(Unit::Minute.secs(), SECONDS_PER_MINUTE as u64);
+        assert_eq!(Unit::Hour.secs(), SECONDS_PER_HOUR as u64);
+        assert_eq!(Unit::Day.secs(), SECONDS_PER_DAY as u64);
+        assert_eq!(Unit::Week.secs(), SECONDS_PER_WEEK as u64);
+        assert_eq!(Unit::Milli.secs(), 0);
+
+        // ALL goes from the largest to the smallest and matches the ordering
+        for pair in Unit::ALL.windows(2) {
+            assert!(pair[0] > pair[1]);
+            assert!(pair[0].in_nanos() > pair[1].in_nanos());
+            assert_eq!(pair[0].in_nanos() % pair[1].in_nanos(), 0);
+        }
+
+        let suffixes: [&str; 8] = Unit::ALL.map(Unit::suffix);
+        assert_eq!(suffixes, ["w", "d", "h", "m", "s", "ms", "\u{b5}s", "ns"]);
+    }
+
+    #[test]
+    fn test_total_in() {
+        assert_eq!(total_in(0, Unit::Week), 0);
+        assert_eq!(total_in(0, Unit::Nano), 0);
+        assert_eq!(total_in(59, Unit::Minute), 0);
+        assert_eq!(total_in(119, Unit::Minute), 1);
+        assert_eq!(total_in(6_000_000, Unit::Week), 9);
+        assert_eq!(total_in(6_000_000, Unit::Day), 69);
+        assert_eq!(total_in(6_000_000, Unit::Hour), 1666);
+        assert_eq!(total_in(6_000_000, Unit::Minute), 100_000);
+        assert_eq!(total_in(6_000_000, Unit::Second), 6_000_000);
+        assert_eq!(total_in(6_000_000, Unit::Milli), 6_000_000_000);
+        assert_eq!(total_in(6_000_000, Unit::Micro), 6_000_000_000_000);
+        assert_eq!(total_in(6_000_000, Unit::Nano), 6_000_000_000_000_000);
+        assert_eq!(total_in(u64::MAX, Unit::Second), u64::MAX);
+        assert_eq!(total_in(u64::MAX, Unit::Nano), u64::MAX);
+        assert_eq!(total_in(18_446_744_074, Unit::Nano), u64::MAX);
+    }
+
+    #[test]
+    fn test_dominant_unit() {
+        assert_eq!(dominant_unit(0), Unit::Second);
+        assert_eq!(dominant_unit(1), Unit::Second);
+        assert_eq!(dominant_unit(59), Unit::Second);
+        assert_eq!(dominant_unit(60), Unit::Minute);
+        assert_eq!(dominant_unit(3599), Unit::Minute);
+        assert_eq!(dominant_unit(3600), Unit::Hour);
+        assert_eq!(dominant_unit(86_399), Unit::Hour);
+        assert_eq!(dominant_unit(86_400), Unit::Day);
+        assert_eq!(dominant_unit(604_799), Unit::Day);
+        assert_eq!(dominant_unit(604_800), Unit::Week);
+        assert_eq!(dominant_unit(1_209_600), Unit::Week);
+        assert_eq!(dominant_unit(u64::MAX), Unit::Week);
+
+        for unit in Unit::ALL.iter().filter(|unit| **unit >= Unit::Second) {
+            assert_eq!(dominant_unit(unit.secs()), *unit);
+            assert_eq!(dominant_unit(unit.secs() * 2 - 1), *unit);
+        }
+    }
+}
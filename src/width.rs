@@ -0,0 +1,137 @@
+use crate::{to_u64, write_dhms, write_ns};
+use core::convert::{TryFrom, TryInto};
+use core::fmt::{self, Debug};
+use core::ops::BitAnd;
+
+/// A [`fmt::Write`] that only counts what is written to it
+#[derive(Default)]
+struct Counter {
+    chars: usize,
+    bytes: usize,
+}
+
+impl fmt::Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.chars += s.chars().count();
+        self.bytes += s.len();
+        Ok(())
+    }
+}
+
+/// Number of characters [`format_dhms`](crate::format_dhms) produces for
+/// `seconds`, without building the string
+///
+/// The output of `format_dhms` is ASCII so this is also its length in bytes.
+///
+/// Example:
+///```
+/// use compound_duration::formatted_len_dhms;
+///
+/// assert_eq!(formatted_len_dhms(6_000_000), "69d10h40m".len());
+/// assert_eq!(formatted_len_dhms(0), 2);
+///```
+#[must_use]
+pub fn formatted_len_dhms<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(seconds: T) -> usize
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    let mut counter = Counter::default();
+    // writing to a Counter never fails
+    write_dhms(&mut counter, to_u64(seconds)).unwrap();
+    counter.chars
+}
+
+/// Number of characters [`format_ns`](crate::format_ns) produces for
+/// `nanos`, without building the string
+///
+/// `µ` counts as one character, use [`formatted_len_ns_bytes`] to size a
+/// buffer.
+///
+/// Example:
+///```
+/// use compound_duration::formatted_len_ns;
+///
+/// assert_eq!(formatted_len_ns(3_000_129_723_u64), "3s129µs723ns".chars().count());
+///```
+#[must_use]
+pub fn formatted_len_ns<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(nanos: T) -> usize
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    ns_counter(to_u64(nanos)).chars
+}
+
+/// Length in bytes of the string [`format_ns`](crate::format_ns) produces
+/// for `nanos`, without building it
+///
+/// `µ` is two bytes in UTF-8, so this is one more than [`formatted_len_ns`]
+/// when the output has microseconds.
+///
+/// Example:
+///```
+/// use compound_duration::formatted_len_ns_bytes;
+///
+/// assert_eq!(formatted_len_ns_bytes(3_000_129_723_u64), "3s129µs723ns".len());
+///```
+#[must_use]
+pub fn formatted_len_ns_bytes<T: TryInto<u64> + TryFrom<u64> + BitAnd<Output = T>>(
+    nanos: T,
+) -> usize
+where
+    <T as TryFrom<u64>>::Error: Debug,
+    <T as TryInto<u64>>::Error: Debug,
+{
+    ns_counter(to_u64(nanos)).bytes
+}
+
+fn ns_counter(nanos: u64) -> Counter {
+    let mut counter = Counter::default();
+    // writing to a Counter never fails
+    write_ns(&mut counter, nanos).unwrap();
+    counter
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{formatted_len_dhms, formatted_len_ns, formatted_len_ns_bytes};
+    use crate::{format_dhms, format_ns};
+
+    #[test]
+    fn test_formatted_len_dhms() {
+        assert_eq!(formatted_len_dhms(0), 2);
+        assert_eq!(formatted_len_dhms(1), 2);
+        assert_eq!(formatted_len_dhms(61), 4);
+        assert_eq!(formatted_len_dhms(6_000_000), 9);
+        assert_eq!(formatted_len_dhms(u64::MAX), "213503982334601d7h15s".len());
+        assert_eq!(formatted_len_dhms(4_294_967_295_u32), 14);
+        for seconds in (0..2_000_000).step_by(97) {
+            assert_eq!(
+                formatted_len_dhms(seconds),
+                format_dhms(seconds).chars().count()
+            );
+        }
+        for seconds in (0..1000).map(|i| i * (u64::MAX / 1000)) {
+            assert_eq!(formatted_len_dhms(seconds), format_dhms(seconds).len());
+        }
+    }
+
+    #[test]
+    fn test_formatted_len_ns() {
+        assert_eq!(formatted_len_ns(0), 3);
+        assert_eq!(formatted_len_ns_bytes(0), 3);
+        assert_eq!(formatted_len_ns(1000), 3);
+        assert_eq!(formatted_len_ns_bytes(1000), 4);
+        assert_eq!(formatted_len_ns(3_000_129_723_u64), 12);
+        assert_eq!(formatted_len_ns_bytes(3_000_129_723_u64), 13);
+        for nanos in (0..2_000_000)
+            .step_by(97)
+            .chain((0..1000).map(|i| i * (u64::MAX / 1000)))
+        {
+            let formatted = format_ns(nanos);
+            assert_eq!(formatted_len_ns(nanos), formatted.chars().count());
+            assert_eq!(formatted_len_ns_bytes(nanos), formatted.len());
+        }
+    }
+}